@@ -0,0 +1,423 @@
+use napi::{bindgen_prelude::*, NapiRaw};
+use napi_derive::napi;
+
+use crate::{
+    check_scale, div_round, format_decimal, parse_decimal, throw_divide_by_zero, to_decimal64,
+    to_decimal64_with, to_i64_with, DecimalRounding, ErrorCode, InputOptions, Int64, Ordering,
+    RawInput,
+};
+
+/// A fixed-point decimal number, stored as a 64-bit unscaled integer and a scale between 0 and 18, so its value is `unscaled / 10^scale`.
+#[napi]
+pub struct Decimal64 {
+    pub(crate) v:     i64,
+    pub(crate) scale: u32,
+}
+
+#[inline]
+fn pow10(n: u32) -> i128 {
+    10i128.pow(n)
+}
+
+/// Converts a decimal number to another scale, rounding it in the given way, returning `None` if overflow occurred.
+#[inline]
+fn rescale_decimal(v: i64, from: u32, to: u32, rounding: DecimalRounding) -> Option<i64> {
+    let v = if to >= from {
+        v as i128 * pow10(to - from)
+    } else {
+        div_round(v as i128, pow10(from - to), rounding)
+    };
+
+    i64::try_from(v).ok()
+}
+
+fn throw_overflow<T>(env: &Env, message: String) -> Result<T> {
+    env.throw_range_error(&message, Some(ErrorCode::Overflow.as_str()))?;
+
+    Err(Error::from_reason(""))
+}
+
+impl Decimal64 {
+    /// Aligns the scales of `self` and `value` to the bigger one.
+    #[inline]
+    fn align(&self, (v, scale): (i64, u32)) -> (i128, i128, u32) {
+        let target = self.scale.max(scale);
+
+        (self.v as i128 * pow10(target - self.scale), v as i128 * pow10(target - scale), target)
+    }
+
+    fn with_scale(env: &Env, (v, from): (i64, u32), scale: Option<u32>) -> Result<Self> {
+        let scale = match scale {
+            Some(scale) => check_scale(env, scale)?,
+            None => from,
+        };
+
+        if scale < from && v as i128 % pow10(from - scale) != 0 {
+            env.throw_range_error(
+                &format!(
+                    "{} cannot be represented with {scale} fractional digits without rounding",
+                    format_decimal(v as i128, from)
+                ),
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            return Err(Error::from_reason(""));
+        }
+
+        match rescale_decimal(v, from, scale, DecimalRounding::Trunc) {
+            Some(v) => Ok(Decimal64 {
+                v,
+                scale,
+            }),
+            None => throw_overflow(
+                env,
+                format!(
+                    "attempt to scale {} to {scale} fractional digits with overflow",
+                    format_decimal(v as i128, from)
+                ),
+            ),
+        }
+    }
+}
+
+#[napi]
+impl Decimal64 {
+    /// @param value The initial value. Strings and numbers are converted as decimal numbers exactly, e.g. `0.1` is `0.1`, and the other values are integers. Default: `0`.
+    /// @param scale The number of fractional digits, between 0 and 18. Default: the number of the fractional digits of `value`. The value must be representable with the scale without rounding, use `rescale` to round it.
+    /// @param options The options of converting `value`.
+    #[napi(constructor)]
+    pub fn new(
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64 | Decimal64")]
+        value: Option<Either3<&Decimal64, &Int64, Unknown>>,
+        scale: Option<u32>,
+        options: Option<InputOptions>,
+    ) -> Result<Self> {
+        let value = match value {
+            Some(value) => to_decimal64_with(&env, value, options)?,
+            None => (0, 0),
+        };
+
+        Decimal64::with_scale(&env, value, scale)
+    }
+
+    /// Parses a decimal number such as `"-1234.5678"`.
+    ///
+    /// @param scale The number of fractional digits, between 0 and 18. Default: the number of the fractional digits of the string.
+    #[napi(factory)]
+    pub fn parse(env: Env, value: String, scale: Option<u32>) -> Result<Self> {
+        let decimal = match parse_decimal(value.as_str()) {
+            Ok(decimal) => decimal,
+            Err(error) => {
+                let input = unsafe { env.create_string(&value)?.raw() };
+
+                error.throw(&env, Some(input))?;
+
+                return Err(Error::from_reason(""));
+            },
+        };
+
+        Decimal64::with_scale(&env, decimal, scale)
+    }
+
+    /// Creates a decimal number from its unscaled value, e.g. an amount in minor units.
+    ///
+    /// @param scale The number of fractional digits, between 0 and 18.
+    /// @param options The options of converting `value`.
+    #[napi(factory, js_name = "fromUnscaled")]
+    pub fn from_unscaled(
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+        scale: u32,
+        options: Option<InputOptions>,
+    ) -> Result<Self> {
+        let v = to_i64_with(&env, value, options)?;
+        let scale = check_scale(&env, scale)?;
+
+        Ok(Decimal64 {
+            v,
+            scale,
+        })
+    }
+
+    /// Gets the number of fractional digits.
+    #[napi(js_name = "getScale")]
+    pub fn get_scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Gets the unscaled value, which is `this * 10^scale`.
+    #[napi(js_name = "getUnscaled")]
+    pub fn get_unscaled(&self) -> Int64 {
+        Int64 {
+            v: self.v
+        }
+    }
+
+    /// Formats this decimal number with a fixed number of fractional digits, like `Number.prototype.toFixed`.
+    ///
+    /// @param digits The number of fractional digits, between 0 and 18. Default: the scale.
+    /// @param rounding How the dropped digits are rounded. Default: `"halfEven"`.
+    #[napi(js_name = "toFixed")]
+    pub fn to_fixed(
+        &self,
+        env: Env,
+        digits: Option<u32>,
+        rounding: Option<DecimalRounding>,
+    ) -> Result<String> {
+        let digits = match digits {
+            Some(digits) => check_scale(&env, digits)?,
+            None => self.scale,
+        };
+
+        let v = if digits >= self.scale {
+            self.v as i128 * pow10(digits - self.scale)
+        } else {
+            div_round(
+                self.v as i128,
+                pow10(self.scale - digits),
+                rounding.unwrap_or(DecimalRounding::HalfEven),
+            )
+        };
+
+        Ok(format_decimal(v, digits))
+    }
+
+    #[allow(clippy::inherent_to_string)]
+    /// To a decimal number in a string, with all of the fractional digits of the scale.
+    #[napi(js_name = "toString")]
+    pub fn to_string(&self) -> String {
+        format_decimal(self.v as i128, self.scale)
+    }
+
+    /// To the nearest number.
+    #[napi(js_name = "toNumber")]
+    pub fn to_number(&self) -> f64 {
+        // `10^scale` is always an exact `f64`, so when the unscaled value is exact too, the division rounds correctly once.
+        if self.v.unsigned_abs() <= 1 << 53 {
+            return self.v as f64 / pow10(self.scale) as f64;
+        }
+
+        // Otherwise converting the unscaled value would round twice. Parsing the decimal string rounds the exact value correctly, and it cannot fail.
+        self.to_string().parse().unwrap()
+    }
+
+    /// Changes the scale, rounding the dropped digits in the given way.
+    ///
+    /// @param scale The number of fractional digits, between 0 and 18.
+    /// @param rounding How the dropped digits are rounded. Default: `"halfEven"`.
+    #[napi]
+    pub fn rescale(
+        &mut self,
+        this: This,
+        env: Env,
+        scale: u32,
+        rounding: Option<DecimalRounding>,
+    ) -> Result<This> {
+        let scale = check_scale(&env, scale)?;
+
+        match rescale_decimal(
+            self.v,
+            self.scale,
+            scale,
+            rounding.unwrap_or(DecimalRounding::HalfEven),
+        ) {
+            Some(v) => {
+                self.v = v;
+                self.scale = scale;
+
+                Ok(this)
+            },
+            None => throw_overflow(
+                &env,
+                format!(
+                    "attempt to rescale {} to {scale} fractional digits with overflow",
+                    self.to_string()
+                ),
+            ),
+        }
+    }
+
+    /// Computes `self += value` exactly. The scale becomes the bigger one of the two operands.
+    #[napi]
+    pub fn add(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64 | Decimal64")]
+        value: Either3<&Decimal64, &Int64, Unknown>,
+    ) -> Result<This> {
+        let (a, b, scale) = self.align(to_decimal64(&env, value)?);
+
+        match i64::try_from(a + b) {
+            Ok(v) => {
+                self.v = v;
+                self.scale = scale;
+
+                Ok(this)
+            },
+            Err(_) => throw_overflow(
+                &env,
+                format!(
+                    "attempt to add {} and {} with overflow",
+                    format_decimal(a, scale),
+                    format_decimal(b, scale)
+                ),
+            ),
+        }
+    }
+
+    /// Computes `self -= value` exactly. The scale becomes the bigger one of the two operands.
+    #[napi]
+    pub fn subtract(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64 | Decimal64")]
+        value: Either3<&Decimal64, &Int64, Unknown>,
+    ) -> Result<This> {
+        let (a, b, scale) = self.align(to_decimal64(&env, value)?);
+
+        match i64::try_from(a - b) {
+            Ok(v) => {
+                self.v = v;
+                self.scale = scale;
+
+                Ok(this)
+            },
+            Err(_) => throw_overflow(
+                &env,
+                format!(
+                    "attempt to subtract {} from {} with overflow",
+                    format_decimal(b, scale),
+                    format_decimal(a, scale)
+                ),
+            ),
+        }
+    }
+
+    /// Computes `self *= value`, keeping the scale of `self` and rounding the dropped digits in the given way.
+    ///
+    /// @param rounding How the dropped digits are rounded. Default: `"halfEven"`.
+    #[napi]
+    pub fn multiply(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64 | Decimal64")]
+        value: Either3<&Decimal64, &Int64, Unknown>,
+        rounding: Option<DecimalRounding>,
+    ) -> Result<This> {
+        let (b, scale) = to_decimal64(&env, value)?;
+
+        let v = div_round(
+            self.v as i128 * b as i128,
+            pow10(scale),
+            rounding.unwrap_or(DecimalRounding::HalfEven),
+        );
+
+        match i64::try_from(v) {
+            Ok(v) => {
+                self.v = v;
+
+                Ok(this)
+            },
+            Err(_) => throw_overflow(
+                &env,
+                format!(
+                    "attempt to multiply {} by {} with overflow",
+                    self.to_string(),
+                    format_decimal(b as i128, scale)
+                ),
+            ),
+        }
+    }
+
+    /// Computes `self /= value`, keeping the scale of `self` and rounding the dropped digits in the given way.
+    ///
+    /// @param rounding How the dropped digits are rounded. Default: `"halfEven"`.
+    #[napi]
+    pub fn divide(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64 | Decimal64")]
+        value: Either3<&Decimal64, &Int64, Unknown>,
+        rounding: Option<DecimalRounding>,
+    ) -> Result<This> {
+        let input = value.raw_input();
+        let (b, scale) = to_decimal64(&env, value)?;
+
+        if b == 0 {
+            return throw_divide_by_zero(
+                &env,
+                format!("attempt to divide {} by zero", self.to_string()),
+                input,
+                Decimal64 {
+                    v: b,
+                    scale,
+                },
+            );
+        }
+
+        let v = div_round(
+            self.v as i128 * pow10(scale),
+            b as i128,
+            rounding.unwrap_or(DecimalRounding::HalfEven),
+        );
+
+        match i64::try_from(v) {
+            Ok(v) => {
+                self.v = v;
+
+                Ok(this)
+            },
+            Err(_) => throw_overflow(
+                &env,
+                format!(
+                    "attempt to divide {} by {} with overflow",
+                    self.to_string(),
+                    format_decimal(b as i128, scale)
+                ),
+            ),
+        }
+    }
+
+    /// Compares `self` with `value` by their values, regardless of their scales.
+    #[napi]
+    pub fn compare(
+        &self,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64 | Decimal64")]
+        value: Either3<&Decimal64, &Int64, Unknown>,
+    ) -> Result<Ordering> {
+        let (a, b, _) = self.align(to_decimal64(&env, value)?);
+
+        Ok(a.cmp(&b).into())
+    }
+
+    /// Computes `self === value` by their values, regardless of their scales.
+    #[napi]
+    pub fn eq(
+        &self,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64 | Decimal64")]
+        value: Either3<&Decimal64, &Int64, Unknown>,
+    ) -> Result<bool> {
+        let (a, b, _) = self.align(to_decimal64(&env, value)?);
+
+        Ok(a == b)
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    /// Clones this `Decimal64` object.
+    #[napi]
+    pub fn clone(&self) -> Decimal64 {
+        Decimal64 {
+            v: self.v, scale: self.scale
+        }
+    }
+}
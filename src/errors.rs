@@ -0,0 +1,114 @@
+use napi::{bindgen_prelude::*, sys, JsRangeError, JsTypeError};
+use napi_derive::napi;
+
+/// The codes of the errors thrown by this library. They can be found in the `code` property of the errors.
+#[napi(string_enum)]
+pub enum ErrorCode {
+    /// A string cannot be parsed as an integer number. The error is a TypeError.
+    #[napi(value = "INT64_PARSE_ERROR")]
+    ParseError,
+    /// A value is out of the acceptable range. The error is a RangeError.
+    #[napi(value = "INT64_OUT_OF_RANGE")]
+    OutOfRange,
+    /// An arithmetic operation overflowed. The error is a RangeError.
+    #[napi(value = "INT64_OVERFLOW")]
+    Overflow,
+    /// A division or a remainder operation has a zero divisor. The error is a RangeError.
+    #[napi(value = "INT64_DIVIDE_BY_ZERO")]
+    DivideByZero,
+    /// A modular inverse does not exist. The error is a RangeError.
+    #[napi(value = "INT64_NOT_INVERTIBLE")]
+    NotInvertible,
+    /// The length of a buffer is not acceptable. The error is a TypeError.
+    #[napi(value = "INT64_BAD_BUFFER_LENGTH")]
+    BadBufferLength,
+    /// The type of a value is not acceptable. The error is a TypeError.
+    #[napi(value = "INT64_UNSUPPORTED_TYPE")]
+    UnsupportedType,
+    /// A number input is not a safe integer while the strict input policy is enabled. The error is a TypeError.
+    #[napi(value = "INT64_UNSAFE_NUMBER")]
+    UnsafeNumber,
+}
+
+impl ErrorCode {
+    #[inline]
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            ErrorCode::ParseError => "INT64_PARSE_ERROR",
+            ErrorCode::OutOfRange => "INT64_OUT_OF_RANGE",
+            ErrorCode::Overflow => "INT64_OVERFLOW",
+            ErrorCode::DivideByZero => "INT64_DIVIDE_BY_ZERO",
+            ErrorCode::NotInvertible => "INT64_NOT_INVERTIBLE",
+            ErrorCode::BadBufferLength => "INT64_BAD_BUFFER_LENGTH",
+            ErrorCode::UnsupportedType => "INT64_UNSUPPORTED_TYPE",
+            ErrorCode::UnsafeNumber => "INT64_UNSAFE_NUMBER",
+        }
+    }
+
+    #[inline]
+    const fn is_range_error(self) -> bool {
+        matches!(
+            self,
+            ErrorCode::OutOfRange
+                | ErrorCode::Overflow
+                | ErrorCode::DivideByZero
+                | ErrorCode::NotInvertible
+        )
+    }
+}
+
+/// An error which is going to be thrown with its code, the offending input and the position where parsing failed.
+pub(crate) struct Int64Error {
+    code:     ErrorCode,
+    message:  String,
+    position: Option<u32>,
+}
+
+impl Int64Error {
+    #[inline]
+    pub(crate) fn new<S: Into<String>>(code: ErrorCode, message: S) -> Self {
+        Int64Error {
+            code,
+            message: message.into(),
+            position: None,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn with_position(mut self, position: u32) -> Self {
+        self.position = Some(position);
+
+        self
+    }
+
+    pub(crate) fn throw(self, env: &Env, input: Option<sys::napi_value>) -> Result<()> {
+        let code = self.code;
+        let error = Error::new(Status::InvalidArg, self.message);
+
+        let mut object = if code.is_range_error() {
+            JsRangeError::from(error).into_unknown(*env)
+        } else {
+            JsTypeError::from(error).into_unknown(*env)
+        }
+        .coerce_to_object()?;
+
+        object.set_named_property("code", code)?;
+
+        if let Some(input) = input {
+            object.set_named_property("input", input)?;
+        }
+
+        if let Some(position) = self.position {
+            object.set_named_property("position", position)?;
+        }
+
+        env.throw(object)
+    }
+}
+
+impl From<Error> for Int64Error {
+    #[inline]
+    fn from(error: Error) -> Self {
+        Int64Error::new(ErrorCode::UnsupportedType, error.reason)
+    }
+}
@@ -0,0 +1,544 @@
+use napi::{bindgen_prelude::*, NapiRaw};
+use napi_derive::napi;
+
+use crate::{
+    check_buffer_bounds, check_frac_bits, check_scale, div_round, format_decimal, isqrt_u128,
+    throw_divide_by_zero, to_i64_with, DecimalRounding, Endianness, ErrorCode, InputOptions, Int64,
+    Int64Error, Ordering, OverflowMode, ParseError, ParseErrorKind,
+};
+
+/// A binary fixed-point number, stored as a 64-bit raw integer and a number of fractional bits between 0 and 63, so its value is `raw / 2^fracBits`.
+#[napi]
+pub struct Fixed64 {
+    pub(crate) v:         i64,
+    pub(crate) frac_bits: u32,
+}
+
+// Q32.32
+const DEFAULT_FRAC_BITS: u32 = 32;
+
+/// Narrows a result to 64 bits in the given overflow mode.
+#[inline]
+fn narrow(v: i128, mode: Option<OverflowMode>) -> i64 {
+    match mode.unwrap_or(OverflowMode::Wrap) {
+        OverflowMode::Wrap => v as i64,
+        OverflowMode::Saturate => v.clamp(i64::MIN as i128, i64::MAX as i128) as i64,
+    }
+}
+
+/// Shifts a raw value from `from` fractional bits to `to` fractional bits, rounding towards negative infinity.
+#[inline]
+fn shift_raw(v: i64, from: u32, to: u32) -> i128 {
+    if to >= from {
+        (v as i128) << (to - from)
+    } else {
+        (v as i128) >> (from - to)
+    }
+}
+
+fn throw_out_of_range<T>(env: &Env, message: String, input: Option<sys::napi_value>) -> Result<T> {
+    Int64Error::new(ErrorCode::OutOfRange, message).throw(env, input)?;
+
+    Err(Error::from_reason(""))
+}
+
+/// Rounds a number to the nearest integer, rounding half-way cases to even like `parse_fixed`. (`f64::round_ties_even` needs Rust 1.77.)
+#[inline]
+fn round_half_even(v: f64) -> f64 {
+    let rounded = v.round();
+
+    if (rounded - v).abs() == 0.5 {
+        (v / 2.0).round() * 2.0
+    } else {
+        rounded
+    }
+}
+
+/// Converts a number to the nearest raw value with `frac_bits` fractional bits, rounding half-way cases to even.
+fn number_to_raw(env: &Env, v: f64, frac_bits: u32) -> Result<i64> {
+    let scaled = round_half_even(v * (1u64 << frac_bits) as f64);
+
+    // the upper bound 2^63 is not included
+    if scaled >= i64::MIN as f64 && scaled < -(i64::MIN as f64) {
+        Ok(scaled as i64)
+    } else {
+        let input = unsafe { env.create_double(v)?.raw() };
+
+        throw_out_of_range(
+            env,
+            format!(
+                "{v} is out of the range of a fixed-point number with {frac_bits} fractional bits"
+            ),
+            Some(input),
+        )
+    }
+}
+
+/// Parses a decimal string such as `"-1234.5678"` to the sign and the magnitude of the nearest raw value with `frac_bits` fractional bits, rounding half-way cases to even. The fractional digits are not limited, so that every `toString` result can be parsed back exactly.
+fn parse_fixed(s: &str, frac_bits: u32) -> std::result::Result<(bool, u128), Int64Error> {
+    let position = |index: usize| s[..index].encode_utf16().count() as u32;
+    let error = |kind, index: usize| {
+        Int64Error::from(ParseError {
+            kind,
+            position: position(index),
+        })
+    };
+
+    let b = s.as_bytes();
+
+    if b.is_empty() {
+        return Err(error(ParseErrorKind::Empty, 0));
+    }
+
+    let (negative, start) = match b[0] {
+        b'-' => (true, 1),
+        b'+' => (false, 1),
+        _ => (false, 0),
+    };
+
+    let overflow_kind =
+        if negative { ParseErrorKind::NegOverflow } else { ParseErrorKind::PosOverflow };
+
+    let mut int = 0u128;
+    let mut int_digits = 0;
+    let mut frac_digits: Option<Vec<u8>> = None;
+
+    for (i, c) in s[start..].char_indices() {
+        let index = start + i;
+
+        if c == '.' && int_digits > 0 && frac_digits.is_none() {
+            frac_digits = Some(Vec::new());
+
+            continue;
+        }
+
+        let digit = match c.to_digit(10) {
+            Some(digit) => digit as u8,
+            None => return Err(error(ParseErrorKind::InvalidDigit, index)),
+        };
+
+        match frac_digits.as_mut() {
+            Some(frac_digits) => frac_digits.push(digit),
+            None => {
+                int = int * 10 + digit as u128;
+
+                // the integer part of a raw value is at most 2^63
+                if int > 1 << 63 {
+                    return Err(error(overflow_kind, index));
+                }
+
+                int_digits += 1;
+            },
+        }
+    }
+
+    let mut frac_digits = match frac_digits {
+        _ if int_digits == 0 => return Err(error(ParseErrorKind::InvalidDigit, s.len())),
+        Some(frac_digits) if frac_digits.is_empty() => {
+            return Err(error(ParseErrorKind::InvalidDigit, s.len()))
+        },
+        frac_digits => frac_digits.unwrap_or_default(),
+    };
+
+    while frac_digits.last() == Some(&0) {
+        frac_digits.pop();
+    }
+
+    // Doubles the fraction in decimal and takes the carry out of its integer part as the next bit, until the fractional bits, the rounding bit and whether the rest is nonzero are known.
+    let mut next_bit = || {
+        let mut carry = 0;
+
+        for digit in frac_digits.iter_mut().rev() {
+            let doubled = *digit * 2 + carry;
+
+            *digit = doubled % 10;
+            carry = doubled / 10;
+        }
+
+        while frac_digits.last() == Some(&0) {
+            frac_digits.pop();
+        }
+
+        carry as u128
+    };
+
+    let mut bits = 0u128;
+
+    for _ in 0..frac_bits {
+        bits = bits << 1 | next_bit();
+    }
+
+    let half = next_bit() == 1;
+    let magnitude = (int << frac_bits) + bits;
+
+    // the parity is of the whole raw value, whose last bit is in the integer part if `frac_bits` is 0
+    if half && (!frac_digits.is_empty() || magnitude & 1 == 1) {
+        Ok((negative, magnitude + 1))
+    } else {
+        Ok((negative, magnitude))
+    }
+}
+
+/// Converts a decimal string to the nearest raw value with `frac_bits` fractional bits, rounding half-way cases to even.
+fn string_to_raw(env: &Env, s: &str, frac_bits: u32) -> Result<i64> {
+    let input = unsafe { env.create_string(s)?.raw() };
+
+    let (negative, magnitude) = match parse_fixed(s, frac_bits) {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            error.throw(env, Some(input))?;
+
+            return Err(Error::from_reason(""));
+        },
+    };
+
+    let raw = if negative { -(magnitude as i128) } else { magnitude as i128 };
+
+    match i64::try_from(raw) {
+        Ok(raw) => Ok(raw),
+        Err(_) => throw_out_of_range(
+            env,
+            format!(
+                "{s} is out of the range of a fixed-point number with {frac_bits} fractional bits"
+            ),
+            Some(input),
+        ),
+    }
+}
+
+/// Converts an operand to its raw value and its number of fractional bits. Numbers have the same fractional bits as `frac_bits`.
+#[inline]
+fn to_fixed_operand(env: &Env, value: Either<&Fixed64, f64>, frac_bits: u32) -> Result<(i64, u32)> {
+    match value {
+        Either::A(fixed64) => Ok((fixed64.v, fixed64.frac_bits)),
+        Either::B(v) => Ok((number_to_raw(env, v, frac_bits)?, frac_bits)),
+    }
+}
+
+#[napi]
+impl Fixed64 {
+    /// @param value The initial value. Strings are parsed as decimal numbers, and the values which cannot be represented exactly are rounded to the nearest. Default: `0`.
+    /// @param fracBits The number of fractional bits, between 0 and 63. Default: the fractional bits of `value` if it is a `Fixed64`, otherwise `32`.
+    #[napi(constructor)]
+    pub fn new(
+        env: Env,
+        #[napi(ts_arg_type = "number | string | Fixed64")] value: Option<
+            Either3<&Fixed64, f64, String>,
+        >,
+        frac_bits: Option<u32>,
+    ) -> Result<Self> {
+        let frac_bits = match (frac_bits, &value) {
+            (Some(frac_bits), _) => check_frac_bits(&env, frac_bits)?,
+            (None, Some(Either3::A(fixed64))) => fixed64.frac_bits,
+            (None, _) => DEFAULT_FRAC_BITS,
+        };
+
+        let v = match value {
+            Some(Either3::A(fixed64)) => {
+                match i64::try_from(shift_raw(fixed64.v, fixed64.frac_bits, frac_bits)) {
+                    Ok(v) => v,
+                    Err(_) => {
+                        return throw_out_of_range(
+                            &env,
+                            format!(
+                                "{} is out of the range of a fixed-point number with {frac_bits} \
+                                 fractional bits",
+                                fixed64.to_string()
+                            ),
+                            None,
+                        )
+                    },
+                }
+            },
+            Some(Either3::B(v)) => number_to_raw(&env, v, frac_bits)?,
+            Some(Either3::C(s)) => string_to_raw(&env, s.as_str(), frac_bits)?,
+            None => 0,
+        };
+
+        Ok(Fixed64 {
+            v,
+            frac_bits,
+        })
+    }
+
+    /// Parses a decimal number such as `"-3.14159"`, rounding it to the nearest fixed-point number. Any number of fractional digits is accepted, so the result of `toString` is parsed back exactly.
+    ///
+    /// @param fracBits The number of fractional bits, between 0 and 63. Default: `32`.
+    #[napi(factory)]
+    pub fn parse(env: Env, value: String, frac_bits: Option<u32>) -> Result<Self> {
+        let frac_bits = check_frac_bits(&env, frac_bits.unwrap_or(DEFAULT_FRAC_BITS))?;
+
+        let v = string_to_raw(&env, value.as_str(), frac_bits)?;
+
+        Ok(Fixed64 {
+            v,
+            frac_bits,
+        })
+    }
+
+    /// Creates a fixed-point number from its raw 64-bit integer.
+    ///
+    /// @param fracBits The number of fractional bits, between 0 and 63. Default: `32`.
+    /// @param options The options of converting `value`.
+    #[napi(factory, js_name = "fromRaw")]
+    pub fn from_raw(
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+        frac_bits: Option<u32>,
+        options: Option<InputOptions>,
+    ) -> Result<Self> {
+        let v = to_i64_with(&env, value, options)?;
+        let frac_bits = check_frac_bits(&env, frac_bits.unwrap_or(DEFAULT_FRAC_BITS))?;
+
+        Ok(Fixed64 {
+            v,
+            frac_bits,
+        })
+    }
+
+    /// Reads the raw 64-bit integer of a fixed-point number from `buffer` at `offset`, like `Int64.fromBuffer`.
+    ///
+    /// @param offset The position where to start reading. Default: `0`.
+    /// @param endianness The byte order. Default: `"LE"`.
+    /// @param fracBits The number of fractional bits, between 0 and 63. Default: `32`.
+    #[napi(factory, js_name = "fromBuffer")]
+    pub fn from_buffer(
+        env: Env,
+        buffer: Buffer,
+        offset: Option<u32>,
+        endianness: Option<Endianness>,
+        frac_bits: Option<u32>,
+    ) -> Result<Self> {
+        let offset = check_buffer_bounds(&env, buffer.len(), offset, 8)?;
+        let frac_bits = check_frac_bits(&env, frac_bits.unwrap_or(DEFAULT_FRAC_BITS))?;
+
+        let mut bytes = [0u8; 8];
+
+        bytes.copy_from_slice(&buffer[offset..offset + 8]);
+
+        Ok(Fixed64 {
+            v: from_bytes!(i64, bytes, endianness),
+            frac_bits,
+        })
+    }
+
+    /// Gets the number of fractional bits.
+    #[napi(js_name = "getFracBits")]
+    pub fn get_frac_bits(&self) -> u32 {
+        self.frac_bits
+    }
+
+    /// Gets the raw 64-bit integer, which is `this * 2^fracBits`.
+    #[napi(js_name = "getRaw")]
+    pub fn get_raw(&self) -> Int64 {
+        Int64 {
+            v: self.v
+        }
+    }
+
+    /// To a 64-bit buffer of the raw integer, which is the same as `Int64.prototype.toBuffer`.
+    ///
+    /// @param endianness The byte order. Default: `"LE"`.
+    #[napi(js_name = "toBuffer")]
+    pub fn to_buffer(&self, endianness: Option<Endianness>) -> Buffer {
+        Buffer::from(to_bytes!(self.v, endianness).to_vec())
+    }
+
+    /// To the nearest number.
+    #[napi(js_name = "toNumber")]
+    pub fn to_number(&self) -> f64 {
+        // dividing by a power of two is exact
+        self.v as f64 / (1u64 << self.frac_bits) as f64
+    }
+
+    #[allow(clippy::inherent_to_string)]
+    /// To a decimal number in a string. Every fixed-point number has a finite decimal representation, so the string is exact.
+    #[napi(js_name = "toString")]
+    pub fn to_string(&self) -> String {
+        let mask = (1u128 << self.frac_bits) - 1;
+        let magnitude = self.v.unsigned_abs() as u128;
+
+        let mut s = if self.v < 0 { String::from("-") } else { String::new() };
+
+        s.push_str((magnitude >> self.frac_bits).to_string().as_str());
+
+        let mut fraction = magnitude & mask;
+
+        if fraction != 0 {
+            s.push('.');
+
+            while fraction != 0 {
+                fraction *= 10;
+
+                s.push(char::from(b'0' + (fraction >> self.frac_bits) as u8));
+
+                fraction &= mask;
+            }
+        }
+
+        s
+    }
+
+    /// Formats this fixed-point number with a fixed number of fractional digits, like `Number.prototype.toFixed`.
+    ///
+    /// @param digits The number of fractional digits, between 0 and 18. Default: `0`.
+    /// @param rounding How the dropped digits are rounded. Default: `"halfEven"`.
+    #[napi(js_name = "toFixed")]
+    pub fn to_fixed(
+        &self,
+        env: Env,
+        digits: Option<u32>,
+        rounding: Option<DecimalRounding>,
+    ) -> Result<String> {
+        let digits = check_scale(&env, digits.unwrap_or(0))?;
+
+        let v = div_round(
+            self.v as i128 * 10i128.pow(digits),
+            1i128 << self.frac_bits,
+            rounding.unwrap_or(DecimalRounding::HalfEven),
+        );
+
+        Ok(format_decimal(v, digits))
+    }
+
+    /// Computes `self += value`.
+    ///
+    /// @param mode How the overflow is treated. Default: `"wrap"`.
+    #[napi]
+    pub fn add(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | Fixed64")] value: Either<&Fixed64, f64>,
+        mode: Option<OverflowMode>,
+    ) -> Result<This> {
+        let (b, frac_bits) = to_fixed_operand(&env, value, self.frac_bits)?;
+
+        self.v = narrow(self.v as i128 + shift_raw(b, frac_bits, self.frac_bits), mode);
+
+        Ok(this)
+    }
+
+    /// Computes `self -= value`.
+    ///
+    /// @param mode How the overflow is treated. Default: `"wrap"`.
+    #[napi]
+    pub fn subtract(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | Fixed64")] value: Either<&Fixed64, f64>,
+        mode: Option<OverflowMode>,
+    ) -> Result<This> {
+        let (b, frac_bits) = to_fixed_operand(&env, value, self.frac_bits)?;
+
+        self.v = narrow(self.v as i128 - shift_raw(b, frac_bits, self.frac_bits), mode);
+
+        Ok(this)
+    }
+
+    /// Computes `self *= value`, rounding the result towards negative infinity.
+    ///
+    /// @param mode How the overflow is treated. Default: `"wrap"`.
+    #[napi]
+    pub fn multiply(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | Fixed64")] value: Either<&Fixed64, f64>,
+        mode: Option<OverflowMode>,
+    ) -> Result<This> {
+        let (b, frac_bits) = to_fixed_operand(&env, value, self.frac_bits)?;
+
+        self.v = narrow((self.v as i128 * b as i128) >> frac_bits, mode);
+
+        Ok(this)
+    }
+
+    /// Computes `self /= value`, rounding the result towards negative infinity.
+    ///
+    /// @param mode How the overflow is treated. Default: `"wrap"`.
+    #[napi]
+    pub fn divide(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | Fixed64")] value: Either<&Fixed64, f64>,
+        mode: Option<OverflowMode>,
+    ) -> Result<This> {
+        let (b, frac_bits) = to_fixed_operand(&env, value, self.frac_bits)?;
+
+        if b == 0 {
+            let input = match value {
+                Either::A(_) => None,
+                Either::B(v) => Some(unsafe { env.create_double(v)?.raw() }),
+            };
+
+            return throw_divide_by_zero(
+                &env,
+                format!("attempt to divide {} by zero", self.to_string()),
+                input,
+                Fixed64 {
+                    v: b,
+                    frac_bits,
+                },
+            );
+        }
+
+        self.v = narrow(
+            div_round((self.v as i128) << frac_bits, b as i128, DecimalRounding::Floor),
+            mode,
+        );
+
+        Ok(this)
+    }
+
+    /// Computes the square root, rounded down.
+    #[napi]
+    pub fn sqrt(&mut self, this: This, env: Env) -> Result<This> {
+        if self.v < 0 {
+            return throw_out_of_range(
+                &env,
+                format!(
+                    "attempt to calculate the square root of a negative number {}",
+                    self.to_string()
+                ),
+                None,
+            );
+        }
+
+        // the root of a value smaller than 2^126 is smaller than 2^63
+        self.v = isqrt_u128((self.v as u128) << self.frac_bits) as i64;
+
+        Ok(this)
+    }
+
+    /// Compares `self` with `value` by their values, regardless of their fractional bits.
+    #[napi]
+    pub fn compare(
+        &self,
+        env: Env,
+        #[napi(ts_arg_type = "number | Fixed64")] value: Either<&Fixed64, f64>,
+    ) -> Result<Ordering> {
+        let (b, frac_bits) = to_fixed_operand(&env, value, self.frac_bits)?;
+
+        let target = self.frac_bits.max(frac_bits);
+
+        let a = shift_raw(self.v, self.frac_bits, target);
+        let b = shift_raw(b, frac_bits, target);
+
+        Ok(a.cmp(&b).into())
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    /// Clones this `Fixed64` object.
+    #[napi]
+    pub fn clone(&self) -> Fixed64 {
+        Fixed64 {
+            v: self.v, frac_bits: self.frac_bits
+        }
+    }
+}
@@ -1,83 +1,1628 @@
-use std::num::ParseIntError;
+use std::fmt::{self, Display, Formatter};
 
-use napi::{bindgen_prelude::*, JsBuffer};
+use napi::{
+    bindgen_prelude::*, JsBuffer, JsObject, JsTypedArray, JsUnknown, NapiRaw, TypedArrayType,
+};
 
-use crate::Int64;
+use crate::{
+    is_strict_input, Decimal64, DecimalRounding, DivisionRounding, ErrorCode, InputOptions, Int128,
+    Int64, Int64Error, NumberRounding, ParseOptions, ShiftMode, UInt128, UInt64, MAX_DECIMAL_SCALE,
+    MAX_SAFE_INTEGER,
+};
 
-pub(crate) fn string_to_i64<S: AsRef<str>>(s: S) -> std::result::Result<i64, ParseIntError> {
-    let s = s.as_ref();
+macro_rules! to_bytes {
+    ($v:expr, $endianness:expr) => {
+        match $endianness.unwrap_or(Endianness::Little) {
+            Endianness::Little => $v.to_le_bytes(),
+            Endianness::Big => $v.to_be_bytes(),
+        }
+    };
+}
 
-    if s.len() > 2 {
-        let b = s.as_bytes();
+macro_rules! from_bytes {
+    ($t:ty, $bytes:expr, $endianness:expr) => {
+        match $endianness.unwrap_or(Endianness::Little) {
+            Endianness::Little => <$t>::from_le_bytes($bytes),
+            Endianness::Big => <$t>::from_be_bytes($bytes),
+        }
+    };
+}
 
-        if b[0] == b'0' {
-            match b[1].to_ascii_lowercase() {
-                b'b' => {
-                    return i64::from_str_radix(&s[2..], 2);
-                },
-                b'o' => {
-                    return i64::from_str_radix(&s[2..], 8);
-                },
-                b'x' => {
-                    return i64::from_str_radix(&s[2..], 16);
-                },
-                _ => (),
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ParseErrorKind {
+    Empty,
+    InvalidDigit,
+    PosOverflow,
+    NegOverflow,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ParseError {
+    pub(crate) kind:     ParseErrorKind,
+    /// The position (in UTF-16 code units, like the indices of JavaScript strings) where parsing failed.
+    pub(crate) position: u32,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let description = match self.kind {
+            ParseErrorKind::Empty => "cannot parse integer from empty string",
+            ParseErrorKind::InvalidDigit => "invalid digit found in string",
+            ParseErrorKind::PosOverflow => "number too large to fit in target type",
+            ParseErrorKind::NegOverflow => "number too small to fit in target type",
+        };
+
+        write!(f, "{description} at position {}", self.position)
+    }
+}
+
+impl From<ParseError> for Int64Error {
+    #[inline]
+    fn from(error: ParseError) -> Self {
+        let code = match error.kind {
+            ParseErrorKind::Empty | ParseErrorKind::InvalidDigit => ErrorCode::ParseError,
+            ParseErrorKind::PosOverflow | ParseErrorKind::NegOverflow => ErrorCode::OutOfRange,
+        };
+
+        Int64Error::new(code, error.to_string()).with_position(error.position)
+    }
+}
+
+/// The primitive integer types which can be converted from JavaScript values.
+pub(crate) trait Integer: Sized + Copy + Display {
+    /// The name used in error messages, e.g. `int64 (long)`.
+    const NAME: &'static str;
+    /// The indefinite article of `NAME`.
+    const ARTICLE: &'static str;
+    const BYTES: usize;
+    const MIN: Self;
+    const MAX: Self;
+    /// The maximum magnitude of a positive value.
+    const MAX_POSITIVE: u128;
+    /// The maximum magnitude of a negative value.
+    const MAX_NEGATIVE: u128;
+
+    fn from_i64(v: i64) -> Option<Self>;
+
+    /// `magnitude` must not be bigger than `MAX_POSITIVE` (or `MAX_NEGATIVE` if `negative` is `true`).
+    fn from_sign_magnitude(negative: bool, magnitude: u128) -> Self;
+
+    /// `data` must have `BYTES` bytes.
+    fn from_le_slice(data: &[u8]) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($t:ty, $name:expr, $article:expr, $max_negative:expr) => {
+        impl Integer for $t {
+            const ARTICLE: &'static str = $article;
+            const BYTES: usize = std::mem::size_of::<$t>();
+            const MAX: Self = <$t>::MAX;
+            const MAX_NEGATIVE: u128 = $max_negative;
+            const MAX_POSITIVE: u128 = <$t>::MAX as u128;
+            const MIN: Self = <$t>::MIN;
+            const NAME: &'static str = $name;
+
+            #[inline]
+            fn from_i64(v: i64) -> Option<Self> {
+                <$t>::try_from(v).ok()
+            }
+
+            #[inline]
+            fn from_sign_magnitude(negative: bool, magnitude: u128) -> Self {
+                if negative {
+                    0u128.wrapping_sub(magnitude) as $t
+                } else {
+                    magnitude as $t
+                }
+            }
+
+            #[inline]
+            fn from_le_slice(data: &[u8]) -> Self {
+                let mut bytes = [0u8; std::mem::size_of::<$t>()];
+
+                bytes.copy_from_slice(data);
+
+                <$t>::from_le_bytes(bytes)
             }
         }
+    };
+}
+
+impl_integer!(i64, "int64 (long)", "an", i64::MIN.unsigned_abs() as u128);
+impl_integer!(u64, "uint64 (unsigned long)", "a", 0);
+impl_integer!(i128, "int128", "an", i128::MIN.unsigned_abs());
+impl_integer!(u128, "uint128", "a", 0);
+
+/// Parses the sign and the magnitude of an integer number. The magnitude must not be bigger than `max_positive` (or `max_negative` if the number is negative).
+///
+/// If `radix` is `None`, the radix is decided by the `0b`, `0o` or `0x` prefix, or is 10 without any prefix.
+///
+/// In the lenient mode, surrounding whitespace, a sign in front of the radix prefix and `_` between digits are allowed.
+fn parse_integer(
+    s: &str,
+    radix: Option<u32>,
+    lenient: bool,
+    max_positive: u128,
+    max_negative: u128,
+) -> std::result::Result<(bool, u128), ParseError> {
+    let error = |kind, index: usize| ParseError {
+        kind,
+        position: s[..index].encode_utf16().count() as u32,
+    };
+
+    let b = s.as_bytes();
+
+    let (mut index, end) = if lenient {
+        let start = s.len() - s.trim_start().len();
+
+        (start, s.trim_end().len().max(start))
+    } else {
+        (0, s.len())
+    };
+
+    if index == end {
+        return Err(error(ParseErrorKind::Empty, index));
+    }
+
+    let parse_sign = |index: &mut usize| match b.get(*index) {
+        Some(b'-') => {
+            *index += 1;
+
+            true
+        },
+        Some(b'+') => {
+            *index += 1;
+
+            false
+        },
+        _ => false,
+    };
+
+    let mut negative = if lenient { parse_sign(&mut index) } else { false };
+
+    let prefix_radix = if end - index > 2 && b[index] == b'0' {
+        match b[index + 1].to_ascii_lowercase() {
+            b'b' => Some(2),
+            b'o' => Some(8),
+            b'x' => Some(16),
+            _ => None,
+        }
+    } else {
+        None
+    };
+
+    let radix = match (radix, prefix_radix) {
+        (None, Some(prefix_radix)) => {
+            index += 2;
+
+            prefix_radix
+        },
+        (None, None) => 10,
+        (Some(radix), Some(prefix_radix)) if lenient && radix == prefix_radix => {
+            index += 2;
+
+            radix
+        },
+        (Some(radix), _) => radix,
+    };
+
+    if !lenient {
+        negative = parse_sign(&mut index);
+    }
+
+    if index == end {
+        return Err(error(ParseErrorKind::InvalidDigit, index));
+    }
+
+    let (max, overflow_kind) = if negative {
+        (max_negative, ParseErrorKind::NegOverflow)
+    } else {
+        (max_positive, ParseErrorKind::PosOverflow)
+    };
+
+    let mut v = 0u128;
+    let mut previous_is_digit = false;
+
+    for (i, c) in s[index..end].char_indices() {
+        if lenient && c == '_' && previous_is_digit {
+            previous_is_digit = false;
+
+            continue;
+        }
+
+        let digit =
+            c.to_digit(radix).ok_or_else(|| error(ParseErrorKind::InvalidDigit, index + i))?;
+
+        v = v
+            .checked_mul(radix as u128)
+            .and_then(|v| v.checked_add(digit as u128))
+            .filter(|v| *v <= max)
+            .ok_or_else(|| error(overflow_kind, index + i))?;
+
+        previous_is_digit = true;
+    }
+
+    if !previous_is_digit {
+        // a trailing `_`
+        return Err(error(ParseErrorKind::InvalidDigit, end - 1));
+    }
+
+    Ok((negative, v))
+}
+
+pub(crate) fn string_to_integer<T: Integer>(
+    s: &str,
+    radix: Option<u32>,
+    lenient: bool,
+) -> std::result::Result<T, ParseError> {
+    let (negative, magnitude) = parse_integer(s, radix, lenient, T::MAX_POSITIVE, T::MAX_NEGATIVE)?;
+
+    Ok(T::from_sign_magnitude(negative, magnitude))
+}
+
+/// Formats the magnitude of an integer number in `radix`, using lowercase letters for the digits bigger than 9.
+pub(crate) fn to_string_radix(mut v: u128, radix: u32, negative: bool) -> String {
+    let radix = radix as u128;
+
+    // 128 binary digits and a sign
+    let mut buffer = [0u8; 129];
+    let mut index = buffer.len();
+
+    loop {
+        let digit = (v % radix) as u8;
+
+        index -= 1;
+        buffer[index] = if digit < 10 { b'0' + digit } else { b'a' + digit - 10 };
+
+        v /= radix;
+
+        if v == 0 {
+            break;
+        }
+    }
+
+    if negative {
+        index -= 1;
+        buffer[index] = b'-';
     }
 
-    s.parse()
+    String::from_utf8(buffer[index..].to_vec()).unwrap()
 }
 
-fn to_i64_inner(value: Either<&Int64, Unknown>) -> Result<i64> {
-    match value {
-        Either::A(int64) => Ok(int64.v),
-        Either::B(value) => {
-            let typ = value.get_type()?;
+/// Rounds a number to a 64-bit integer. In the modes except strict, NaN becomes `0` and the numbers out of range are clamped.
+fn f64_to_i64(v: f64, rounding: NumberRounding) -> std::result::Result<i64, Int64Error> {
+    let rounded = match rounding {
+        NumberRounding::Trunc => v.trunc(),
+        NumberRounding::Floor => v.floor(),
+        NumberRounding::Ceil => v.ceil(),
+        NumberRounding::Round => v.round(),
+        NumberRounding::Strict => {
+            if !v.is_finite() {
+                return Err(Int64Error::new(
+                    ErrorCode::OutOfRange,
+                    "a non-finite number cannot be converted to an int64 (long) value",
+                ));
+            } else if v.fract() != 0.0 {
+                return Err(Int64Error::new(
+                    ErrorCode::OutOfRange,
+                    format!("{v} is not an integer"),
+                ));
+            } else if !(-9223372036854775808.0..9223372036854775808.0).contains(&v) {
+                return Err(Int64Error::new(
+                    ErrorCode::OutOfRange,
+                    format!("{v} is out of the range of an int64 (long) value"),
+                ));
+            }
 
-            match typ {
-                ValueType::Number => value.coerce_to_number()?.get_int64(),
-                ValueType::String => {
-                    string_to_i64(value.coerce_to_string()?.into_utf8()?.as_str()?)
-                        .map_err(|err| Error::from_reason(err.to_string()))
+            v
+        },
+    };
+
+    // `as` saturates on overflow and converts NaN to 0
+    Ok(rounded as i64)
+}
+
+/// Rounds a number to a 64-bit integer, throwing the error with its input if failed.
+pub(crate) fn number_to_i64(env: &Env, v: f64, rounding: NumberRounding) -> Result<i64> {
+    match f64_to_i64(v, rounding) {
+        Ok(v) => Ok(v),
+        Err(error) => {
+            let input = unsafe { env.create_double(v)?.raw() };
+
+            error.throw(env, Some(input))?;
+
+            Err(Error::from_reason(""))
+        },
+    }
+}
+
+/// Checks whether `radix` is between 2 and 36 (inclusive).
+pub(crate) fn check_radix(env: &Env, radix: Option<u32>) -> Result<Option<u32>> {
+    match radix {
+        Some(radix) if !(2..=36).contains(&radix) => {
+            env.throw_range_error(
+                &format!("the radix must be between 2 and 36, but it is {radix}"),
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            Err(Error::from_reason(""))
+        },
+        _ => Ok(radix),
+    }
+}
+
+/// Parses a string with a radix or parsing options, throwing the error with its input if failed.
+pub(crate) fn parse_string<T: Integer>(
+    env: &Env,
+    s: String,
+    options: Option<Either<u32, ParseOptions>>,
+) -> Result<T> {
+    let (radix, lenient) = match options {
+        Some(Either::A(radix)) => (Some(radix), false),
+        Some(Either::B(options)) => (options.radix, options.lenient.unwrap_or(false)),
+        None => (None, false),
+    };
+
+    let radix = check_radix(env, radix)?;
+
+    match string_to_integer(s.as_str(), radix, lenient) {
+        Ok(v) => Ok(v),
+        Err(error) => {
+            let input = unsafe { env.create_string(&s)?.raw() };
+
+            Int64Error::from(error).throw(env, Some(input))?;
+
+            Err(Error::from_reason(""))
+        },
+    }
+}
+
+/// Parses a decimal number such as `-1234.5678` as its unscaled value and its scale, which is the number of the fractional digits.
+pub(crate) fn parse_decimal(s: &str) -> std::result::Result<(i64, u32), Int64Error> {
+    let position = |index: usize| s[..index].encode_utf16().count() as u32;
+    let error = |kind, index: usize| {
+        Int64Error::from(ParseError {
+            kind,
+            position: position(index),
+        })
+    };
+
+    let b = s.as_bytes();
+
+    if b.is_empty() {
+        return Err(error(ParseErrorKind::Empty, 0));
+    }
+
+    let (negative, start) = match b[0] {
+        b'-' => (true, 1),
+        b'+' => (false, 1),
+        _ => (false, 0),
+    };
+
+    let (max, overflow_kind) = if negative {
+        (i64::MIN.unsigned_abs(), ParseErrorKind::NegOverflow)
+    } else {
+        (i64::MAX as u64, ParseErrorKind::PosOverflow)
+    };
+
+    let mut v = 0u64;
+    let mut digits = 0;
+    let mut scale = None;
+
+    for (i, c) in s[start..].char_indices() {
+        let index = start + i;
+
+        if c == '.' && digits > 0 && scale.is_none() {
+            scale = Some(0);
+
+            continue;
+        }
+
+        let digit = match c.to_digit(10) {
+            Some(digit) => digit as u64,
+            None => return Err(error(ParseErrorKind::InvalidDigit, index)),
+        };
+
+        if let Some(scale) = scale.as_mut() {
+            if *scale == MAX_DECIMAL_SCALE {
+                return Err(Int64Error::new(
+                    ErrorCode::OutOfRange,
+                    format!(
+                        "a decimal number cannot have more than {MAX_DECIMAL_SCALE} fractional \
+                         digits"
+                    ),
+                )
+                .with_position(position(index)));
+            }
+
+            *scale += 1;
+        }
+
+        v = match v.checked_mul(10).and_then(|v| v.checked_add(digit)) {
+            Some(v) if v <= max => v,
+            _ => return Err(error(overflow_kind, index)),
+        };
+
+        digits += 1;
+    }
+
+    match scale {
+        _ if digits == 0 => Err(error(ParseErrorKind::InvalidDigit, s.len())),
+        Some(0) => Err(error(ParseErrorKind::InvalidDigit, s.len())),
+        _ => {
+            let v = if negative { 0u64.wrapping_sub(v) as i64 } else { v as i64 };
+
+            Ok((v, scale.unwrap_or(0)))
+        },
+    }
+}
+
+/// Formats an unscaled value with `scale` fractional digits.
+pub(crate) fn format_decimal(v: i128, scale: u32) -> String {
+    let digits = v.unsigned_abs().to_string();
+    let scale = scale as usize;
+
+    let digits = if digits.len() <= scale {
+        format!("{}{digits}", "0".repeat(scale + 1 - digits.len()))
+    } else {
+        digits
+    };
+
+    let (integer, fraction) = digits.split_at(digits.len() - scale);
+    let sign = if v < 0 { "-" } else { "" };
+
+    if fraction.is_empty() {
+        format!("{sign}{integer}")
+    } else {
+        format!("{sign}{integer}.{fraction}")
+    }
+}
+
+/// Reads the `low` and `high` 32-bit words of a Long.js-like object as a 64-bit integer, along with its `unsigned` property. Returns `None` if the words are not numbers.
+fn get_long_bits(object: &JsObject) -> Result<Option<(u64, bool)>> {
+    let low: JsUnknown = object.get_named_property("low")?;
+    let high: JsUnknown = object.get_named_property("high")?;
+
+    if low.get_type()? != ValueType::Number || high.get_type()? != ValueType::Number {
+        return Ok(None);
+    }
+
+    let low = low.coerce_to_number()?.get_int32()? as u32 as u64;
+    let high = high.coerce_to_number()?.get_int32()? as u32 as u64;
+
+    let unsigned: JsUnknown = object.get_named_property("unsigned")?;
+
+    let unsigned =
+        unsigned.get_type()? == ValueType::Boolean && unsigned.coerce_to_bool()?.get_value()?;
+
+    Ok(Some((high << 32 | low, unsigned)))
+}
+
+fn check_safe_number(v: f64) -> std::result::Result<i64, Int64Error> {
+    let message = if !v.is_finite() {
+        "a non-finite number is not a safe integer".to_string()
+    } else if v.fract() != 0.0 {
+        format!("{v} is not an integer")
+    } else if v.abs() > MAX_SAFE_INTEGER as f64 {
+        format!(
+            "{v} is beyond the safe integer range, use a string, a bigint, a buffer or an \
+             instance instead"
+        )
+    } else {
+        return Ok(v as i64);
+    };
+
+    Err(Int64Error::new(ErrorCode::UnsafeNumber, message))
+}
+
+fn to_integer_inner<T: Integer>(
+    env: &Env,
+    value: Unknown,
+    strict: bool,
+) -> std::result::Result<T, Int64Error> {
+    let typ = value.get_type()?;
+
+    match typ {
+        ValueType::Number => {
+            let number = value.coerce_to_number()?;
+
+            let v = if strict {
+                check_safe_number(number.get_double()?)?
+            } else {
+                f64_to_i64(number.get_double()?, NumberRounding::Trunc)?
+            };
+
+            T::from_i64(v).ok_or_else(|| {
+                Int64Error::new(
+                    ErrorCode::OutOfRange,
+                    format!("{v} is out of the range of {} {} value", T::ARTICLE, T::NAME),
+                )
+            })
+        },
+        ValueType::BigInt => {
+            let bigint = unsafe { BigInt::from_napi_value(env.raw(), value.raw())? };
+
+            let magnitude = match bigint.words.as_slice() {
+                [low] => Some(*low as u128),
+                [low, high] => Some(*low as u128 | (*high as u128) << 64),
+                _ => None,
+            };
+
+            let max = if bigint.sign_bit { T::MAX_NEGATIVE } else { T::MAX_POSITIVE };
+
+            match magnitude {
+                Some(magnitude) if magnitude <= max => {
+                    Ok(T::from_sign_magnitude(bigint.sign_bit, magnitude))
                 },
-                ValueType::Object => {
-                    if value.is_buffer()? {
-                        let buffer = unsafe { value.cast::<JsBuffer>() };
-
-                        let buffer_value = buffer.into_value()?;
-                        let data = buffer_value.as_ref();
-
-                        if data.len() == 8 {
-                            Ok(i64::from_le_bytes([
-                                data[0], data[1], data[2], data[3], data[4], data[5], data[6],
-                                data[7],
-                            ]))
+                _ => Err(Int64Error::new(
+                    ErrorCode::OutOfRange,
+                    format!(
+                        "the bigint value is out of the range of {} {} value, from {} to {}",
+                        T::ARTICLE,
+                        T::NAME,
+                        T::MIN,
+                        T::MAX
+                    ),
+                )),
+            }
+        },
+        ValueType::String => {
+            Ok(string_to_integer(value.coerce_to_string()?.into_utf8()?.as_str()?, None, false)?)
+        },
+        ValueType::Object => {
+            if value.is_buffer()? {
+                let buffer = unsafe { value.cast::<JsBuffer>() };
+
+                let buffer_value = buffer.into_value()?;
+                let data = buffer_value.as_ref();
+
+                if data.len() == T::BYTES {
+                    Ok(T::from_le_slice(data))
+                } else {
+                    Err(Int64Error::new(
+                        ErrorCode::BadBufferLength,
+                        format!("the length of the input buffer is not {}", T::BYTES),
+                    ))
+                }
+            } else {
+                let object = unsafe { value.cast::<JsObject>() };
+
+                match get_long_bits(&object)? {
+                    Some((bits, unsigned)) => {
+                        let (negative, magnitude) = if unsigned {
+                            (false, bits as u128)
                         } else {
-                            Err(Error::from_reason("the length of the input buffer is not 8"))
+                            let v = bits as i64;
+
+                            (v < 0, v.unsigned_abs() as u128)
+                        };
+
+                        let max = if negative { T::MAX_NEGATIVE } else { T::MAX_POSITIVE };
+
+                        if magnitude <= max {
+                            Ok(T::from_sign_magnitude(negative, magnitude))
+                        } else {
+                            Err(Int64Error::new(
+                                ErrorCode::OutOfRange,
+                                format!(
+                                    "the {{ low, high }} object is out of the range of {} {} \
+                                     value, from {} to {}",
+                                    T::ARTICLE,
+                                    T::NAME,
+                                    T::MIN,
+                                    T::MAX
+                                ),
+                            ))
                         }
+                    },
+                    None => Err(Int64Error::new(
+                        ErrorCode::UnsupportedType,
+                        format!(
+                            "the {} value is an object, but it's neither a buffer nor a {{ low, \
+                             high }} object",
+                            T::NAME
+                        ),
+                    )),
+                }
+            }
+        },
+        _ => Err(Int64Error::new(
+            ErrorCode::UnsupportedType,
+            format!(
+                "{} {} value should be a number, a bigint, a string or an object",
+                T::ARTICLE,
+                T::NAME
+            ),
+        )),
+    }
+}
+
+/// Converts a JavaScript value to an integer, throwing the error with its input if failed. `strict` decides whether a number must be a safe integer.
+fn to_integer<T: Integer>(env: &Env, value: Unknown, strict: bool) -> Result<T> {
+    let input = unsafe { value.raw() };
+
+    match to_integer_inner(env, value, strict) {
+        Ok(value) => Ok(value),
+        Err(error) => {
+            error.throw(env, Some(input))?;
+
+            Err(Error::from_reason(""))
+        },
+    }
+}
+
+/// Gets the raw JavaScript value of an operand before it is converted, so that it can be the `input` of an error found afterwards. Returns `None` if the operand is an instance, whose JavaScript value is not kept.
+pub(crate) trait RawInput {
+    fn raw_input(&self) -> Option<sys::napi_value>;
+}
+
+impl<A> RawInput for Either<A, Unknown> {
+    #[inline]
+    fn raw_input(&self) -> Option<sys::napi_value> {
+        match self {
+            Either::A(_) => None,
+            Either::B(value) => Some(unsafe { value.raw() }),
+        }
+    }
+}
+
+impl<A, B> RawInput for Either3<A, B, Unknown> {
+    #[inline]
+    fn raw_input(&self) -> Option<sys::napi_value> {
+        match self {
+            Either3::C(value) => Some(unsafe { value.raw() }),
+            _ => None,
+        }
+    }
+}
+
+/// Throws a divide-by-zero error whose `input` is the raw divisor, or a new instance of `divisor` if the raw divisor is not kept.
+pub(crate) fn throw_divide_by_zero<T, D: ToNapiValue>(
+    env: &Env,
+    message: String,
+    input: Option<sys::napi_value>,
+    divisor: D,
+) -> Result<T> {
+    let input = match input {
+        Some(input) => input,
+        None => unsafe { D::to_napi_value(env.raw(), divisor)? },
+    };
+
+    Int64Error::new(ErrorCode::DivideByZero, message).throw(env, Some(input))?;
+
+    Err(Error::from_reason(""))
+}
+
+/// Checks whether `size` bytes can be accessed from `offset` in a buffer whose length is `length`, and returns the offset.
+pub(crate) fn check_buffer_bounds(
+    env: &Env,
+    length: usize,
+    offset: Option<u32>,
+    size: usize,
+) -> Result<usize> {
+    let offset = offset.unwrap_or(0) as usize;
+
+    if offset + size > length {
+        env.throw_range_error(
+            &format!(
+                "the offset {offset} is out of the bounds of the buffer, {size} bytes are needed \
+                 but the length of the buffer is {length}"
+            ),
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    }
+
+    Ok(offset)
+}
+
+/// Computes the quotient and the remainder of `a / b`, rounding the quotient in the given way and wrapping around at the boundary of an 64-bit integer.
+///
+/// `b` must not be zero.
+pub(crate) fn div_rem(a: i64, b: i64, rounding: DivisionRounding) -> (i64, i64) {
+    let q = a.wrapping_div(b);
+    let r = a.wrapping_rem(b);
+
+    match rounding {
+        DivisionRounding::Trunc => (q, r),
+        DivisionRounding::Floor => {
+            if r != 0 && (r < 0) != (b < 0) {
+                (q.wrapping_sub(1), r + b)
+            } else {
+                (q, r)
+            }
+        },
+        DivisionRounding::Ceil => {
+            if r != 0 && (r < 0) == (b < 0) {
+                (q.wrapping_add(1), r - b)
+            } else {
+                (q, r)
+            }
+        },
+        DivisionRounding::Euclid => (a.wrapping_div_euclid(b), a.wrapping_rem_euclid(b)),
+    }
+}
+
+/// Computes `a / b`, rounding the quotient in the given way.
+///
+/// `b` must not be zero.
+pub(crate) fn div_round(a: i128, b: i128, rounding: DecimalRounding) -> i128 {
+    let q = a / b;
+    let r = a % b;
+
+    if r == 0 {
+        return q;
+    }
+
+    let negative = (a < 0) != (b < 0);
+    let away = if negative { q - 1 } else { q + 1 };
+
+    match rounding {
+        DecimalRounding::Trunc => q,
+        DecimalRounding::Floor => {
+            if negative {
+                away
+            } else {
+                q
+            }
+        },
+        DecimalRounding::Ceil => {
+            if negative {
+                q
+            } else {
+                away
+            }
+        },
+        DecimalRounding::HalfUp | DecimalRounding::HalfEven => {
+            match (r.unsigned_abs() * 2).cmp(&b.unsigned_abs()) {
+                std::cmp::Ordering::Less => q,
+                std::cmp::Ordering::Greater => away,
+                std::cmp::Ordering::Equal => {
+                    if matches!(rounding, DecimalRounding::HalfUp) || q % 2 != 0 {
+                        away
                     } else {
-                        Err(Error::from_reason(
-                            "the int64 (long) value is an object, but it's not a buffer",
-                        ))
+                        q
                     }
                 },
-                _ => Err(Error::from_reason(
-                    "an int64 (long) value should be a number, a string or an object",
-                )),
             }
         },
     }
 }
 
+fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// Computes the greatest common divisor of `a` and `b`, returning `None` if it is `2^63`.
+pub(crate) fn checked_gcd(a: i64, b: i64) -> Option<i64> {
+    i64::try_from(gcd_u64(a.unsigned_abs(), b.unsigned_abs())).ok()
+}
+
+/// Computes the least common multiple of `a` and `b`, returning `None` if overflow occurred.
+pub(crate) fn checked_lcm(a: i64, b: i64) -> Option<i64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+
+    let a = a.unsigned_abs();
+    let b = b.unsigned_abs();
+
+    i64::try_from((a / gcd_u64(a, b)) as u128 * b as u128).ok()
+}
+
+/// Computes the integer square root of `v`, rounded down.
+pub(crate) fn isqrt_u64(v: u64) -> u64 {
+    // the estimation is close enough to be corrected in a few steps
+    let mut r = (v as f64).sqrt() as u64;
+
+    while r as u128 * r as u128 > v as u128 {
+        r -= 1;
+    }
+
+    while (r + 1) as u128 * (r + 1) as u128 <= v as u128 {
+        r += 1;
+    }
+
+    r
+}
+
+/// Computes the integer square root of `v`, rounded down.
+pub(crate) fn isqrt_u128(v: u128) -> u128 {
+    if v < 2 {
+        return v;
+    }
+
+    // start from a power of two which is not smaller than the root, so that Newton's method decreases monotonically
+    let mut r = 1u128 << ((129 - v.leading_zeros()) / 2);
+
+    loop {
+        let next = (r + v / r) / 2;
+
+        if next >= r {
+            return r;
+        }
+
+        r = next;
+    }
+}
+
+/// Computes the integer logarithm of `v` in `base`, rounded down.
+///
+/// `v` must be bigger than zero, and `base` must not be smaller than 2.
+pub(crate) fn ilog_u64(mut v: u64, base: u64) -> u32 {
+    let mut n = 0;
+
+    while v >= base {
+        v /= base;
+        n += 1;
+    }
+
+    n
+}
+
+/// Computes `(a + b) / 2` without overflow, rounding towards zero.
+pub(crate) fn midpoint_i64(a: i64, b: i64) -> i64 {
+    ((a as i128 + b as i128) / 2) as i64
+}
+
+/// Converts `m` and checks whether it can be used as a modulus, and returns it as an unsigned integer.
+pub(crate) fn check_modulus(env: &Env, m: Either<&Int64, Unknown>) -> Result<u64> {
+    let input = m.raw_input();
+    let m = to_i64(env, m)?;
+
+    if m == 0 {
+        return throw_divide_by_zero(
+            env,
+            "the modulus must not be zero".to_string(),
+            input,
+            Int64 {
+                v: m
+            },
+        );
+    } else if m < 0 {
+        let input = match input {
+            Some(input) => input,
+            None => unsafe {
+                Int64::to_napi_value(env.raw(), Int64 {
+                    v: m
+                })?
+            },
+        };
+
+        Int64Error::new(
+            ErrorCode::OutOfRange,
+            format!("the modulus must be bigger than zero, but it is {m}"),
+        )
+        .throw(env, Some(input))?;
+
+        return Err(Error::from_reason(""));
+    }
+
+    Ok(m as u64)
+}
+
+/// Computes `(a + b) mod m` with a 128-bit intermediate result.
+pub(crate) fn add_mod_i64(a: i64, b: i64, m: u64) -> i64 {
+    (a as i128 + b as i128).rem_euclid(m as i128) as i64
+}
+
+/// Computes `(a * b) mod m` with a 128-bit intermediate result.
+pub(crate) fn mul_mod_i64(a: i64, b: i64, m: u64) -> i64 {
+    (a as i128 * b as i128).rem_euclid(m as i128) as i64
+}
+
+/// Computes `(a ^ e) mod m` by squaring, with 128-bit intermediate results.
+pub(crate) fn pow_mod_i64(a: i64, mut e: u64, m: u64) -> i64 {
+    let m = m as u128;
+
+    let mut base = (a as i128).rem_euclid(m as i128) as u128;
+    let mut v = 1 % m;
+
+    while e > 0 {
+        if e & 1 == 1 {
+            v = v * base % m;
+        }
+
+        base = base * base % m;
+        e >>= 1;
+    }
+
+    v as i64
+}
+
+/// Computes the modular inverse of `a` with the extended Euclidean algorithm, returning `None` if `a` and `m` are not coprime.
+pub(crate) fn mod_inverse_i64(a: i64, m: u64) -> Option<i64> {
+    let m = m as i128;
+
+    let (mut r0, mut r1) = (m, (a as i128).rem_euclid(m));
+    let (mut t0, mut t1) = (0i128, 1i128);
+
+    while r1 != 0 {
+        let q = r0 / r1;
+
+        (r0, r1) = (r1, r0 - q * r1);
+        (t0, t1) = (t1, t0 - q * t1);
+    }
+
+    if r0 == 1 {
+        Some(t0.rem_euclid(m) as i64)
+    } else {
+        None
+    }
+}
+
+#[inline]
+fn mul_mod_u64(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+fn pow_mod_u64(mut a: u64, mut e: u64, m: u64) -> u64 {
+    let mut v = 1 % m;
+
+    while e > 0 {
+        if e & 1 == 1 {
+            v = mul_mod_u64(v, a, m);
+        }
+
+        a = mul_mod_u64(a, a, m);
+        e >>= 1;
+    }
+
+    v
+}
+
+const SMALL_PRIMES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Tests whether `n` is a prime number with the Miller-Rabin test. The bases are known to make the test deterministic for all 64-bit integers.
+pub(crate) fn is_prime_u64(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+
+    for p in SMALL_PRIMES {
+        if n % p == 0 {
+            return n == p;
+        }
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+
+    'bases: for a in [2, 325, 9375, 28178, 450775, 9780504, 1795265022] {
+        let a = a % n;
+
+        if a == 0 {
+            continue;
+        }
+
+        let mut x = pow_mod_u64(a, d, n);
+
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+
+        for _ in 1..s {
+            x = mul_mod_u64(x, x, n);
+
+            if x == n - 1 {
+                continue 'bases;
+            }
+        }
+
+        return false;
+    }
+
+    true
+}
+
+/// Finds a non-trivial divisor of `n` with Pollard's rho algorithm.
+///
+/// `n` must be an odd composite number.
+fn pollard_rho(n: u64) -> u64 {
+    for c in 1.. {
+        let f = |x: u64| ((x as u128 * x as u128 + c as u128) % n as u128) as u64;
+
+        let mut x = 2;
+        let mut y = 2;
+        let mut d = 1;
+
+        while d == 1 {
+            x = f(x);
+            y = f(f(y));
+            d = gcd_u64(x.abs_diff(y), n);
+        }
+
+        if d != n {
+            return d;
+        }
+    }
+
+    unreachable!()
+}
+
+/// Computes the prime factors of `n` with multiplicities, in ascending order.
+pub(crate) fn factorize_u64(mut n: u64) -> Vec<u64> {
+    let mut factors = Vec::new();
+
+    for p in SMALL_PRIMES {
+        while n % p == 0 {
+            factors.push(p);
+            n /= p;
+        }
+    }
+
+    let mut composites = vec![n];
+
+    while let Some(n) = composites.pop() {
+        if n == 1 {
+            continue;
+        }
+
+        if is_prime_u64(n) {
+            factors.push(n);
+        } else {
+            let d = pollard_rho(n);
+
+            composites.push(d);
+            composites.push(n / d);
+        }
+    }
+
+    factors.sort_unstable();
+
+    factors
+}
+
+/// Finds the smallest prime number bigger than `n`, returning `None` if overflow occurred.
+pub(crate) fn next_prime_i64(n: i64) -> Option<i64> {
+    if n < 2 {
+        return Some(2);
+    }
+
+    let mut n = n;
+
+    loop {
+        n = n.checked_add(1)?;
+
+        if is_prime_u64(n as u64) {
+            return Some(n);
+        }
+    }
+}
+
+/// Finds the biggest prime number smaller than `n`, returning `None` if there is no such number.
+pub(crate) fn prev_prime_i64(n: i64) -> Option<i64> {
+    (2..n).rev().find(|&n| is_prime_u64(n as u64))
+}
+
+/// Counts the number of bits needed to represent `v` in two's complement, excluding the sign bit.
+pub(crate) fn bit_length_i64(v: i64) -> u32 {
+    if v < 0 {
+        64 - v.leading_ones()
+    } else {
+        64 - v.leading_zeros()
+    }
+}
+
+/// Computes the smallest power of two which is not smaller than `v`, returning `None` if overflow occurred.
+pub(crate) fn checked_next_power_of_two(v: i64) -> Option<i64> {
+    if v <= 1 {
+        Some(1)
+    } else {
+        i64::try_from((v as u64).next_power_of_two()).ok()
+    }
+}
+
+/// Checks whether `index` is between 0 and 63, and returns it.
+pub(crate) fn check_bit_index(env: &Env, index: i64) -> Result<u32> {
+    if index < 0 {
+        env.throw_range_error(
+            "the bit index must not be smaller than zero",
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    } else if index > 63 {
+        env.throw_range_error(
+            &format!("the bit index must not be bigger than 63, but it is {index}"),
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    }
+
+    Ok(index as u32)
+}
+
+/// Checks whether the bit field which starts at `offset` and has `width` bits is in 64 bits, and returns them.
+pub(crate) fn check_bit_field(env: &Env, offset: i64, width: i64) -> Result<(u32, u32)> {
+    if offset < 0 {
+        env.throw_range_error(
+            "the bit offset must not be smaller than zero",
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    } else if width < 0 {
+        env.throw_range_error(
+            "the bit width must not be smaller than zero",
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    } else if offset > 64 || width > 64 - offset {
+        env.throw_range_error(
+            &format!(
+                "the bit field at offset {offset} with a width of {width} is out of the 64 bits"
+            ),
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    }
+
+    Ok((offset as u32, width as u32))
+}
+
+#[inline]
+fn bit_field_mask(width: u32) -> u64 {
+    if width == 64 {
+        u64::MAX
+    } else {
+        (1 << width) - 1
+    }
+}
+
+/// Extracts the bit field of `v` which starts at `offset` and has `width` bits, sign-extending it if `signed` is `true`.
+///
+/// `offset + width` must not be bigger than 64.
+pub(crate) fn extract_bits_i64(v: i64, offset: u32, width: u32, signed: bool) -> i64 {
+    if width == 0 {
+        return 0;
+    }
+
+    let field = (v as u64 >> offset) & bit_field_mask(width);
+
+    if signed {
+        ((field << (64 - width)) as i64) >> (64 - width)
+    } else {
+        field as i64
+    }
+}
+
+/// Replaces the bit field of `v` which starts at `offset` and has `width` bits with the lowest `width` bits of `field`.
+///
+/// `offset + width` must not be bigger than 64.
+pub(crate) fn insert_bits_i64(v: i64, offset: u32, width: u32, field: i64) -> i64 {
+    if width == 0 {
+        return v;
+    }
+
+    let mask = bit_field_mask(width);
+
+    ((v as u64 & !(mask << offset)) | ((field as u64 & mask) << offset)) as i64
+}
+
+/// Checks whether `count` is acceptable as the bit count of a shift or a rotation in `mode`, and returns it.
+pub(crate) fn check_shift_count(
+    env: &Env,
+    count: u32,
+    mode: Option<ShiftMode>,
+    operation: &str,
+) -> Result<u32> {
+    if let Some(ShiftMode::Strict) = mode {
+        if count >= 64 {
+            env.throw_range_error(
+                &format!(
+                    "the bit count for {operation} must be smaller than 64 in the strict mode, \
+                     but it is {count}"
+                ),
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            return Err(Error::from_reason(""));
+        }
+    }
+
+    Ok(count)
+}
+
+/// Computes `a << b` in `mode`.
+pub(crate) fn shl_i64(a: i64, b: u32, mode: Option<ShiftMode>) -> i64 {
+    match mode {
+        Some(ShiftMode::Saturate) if b >= 64 => 0,
+        _ => a.wrapping_shl(b),
+    }
+}
+
+/// Computes `a >> b` in `mode`.
+pub(crate) fn shr_i64(a: i64, b: u32, mode: Option<ShiftMode>) -> i64 {
+    match mode {
+        Some(ShiftMode::Saturate) if b >= 64 => a >> 63,
+        _ => a.wrapping_shr(b),
+    }
+}
+
+/// Computes `a >>> b` in `mode`.
+pub(crate) fn shr_unsigned_i64(a: i64, b: u32, mode: Option<ShiftMode>) -> i64 {
+    match mode {
+        Some(ShiftMode::Saturate) if b >= 64 => 0,
+        _ => (a as u64).wrapping_shr(b) as i64,
+    }
+}
+
+pub(crate) fn checked_shl(a: i64, b: u32) -> Option<i64> {
+    if b >= 64 {
+        return if a == 0 { Some(0) } else { None };
+    }
+
+    let v = a << b;
+
+    if v >> b == a {
+        Some(v)
+    } else {
+        None
+    }
+}
+
+/// Gets whether a number input must be a safe integer, by the per-call options or else by the strict input policy.
+#[inline]
+pub(crate) fn is_strict(options: Option<InputOptions>) -> bool {
+    options.and_then(|options| options.strict).unwrap_or_else(is_strict_input)
+}
+
 #[inline]
 pub(crate) fn to_i64(env: &Env, value: Either<&Int64, Unknown>) -> Result<i64> {
-    match to_i64_inner(value) {
-        Ok(value) => Ok(value),
-        Err(error) => {
-            env.throw_type_error(&error.reason, None)?;
+    to_i64_with(env, value, None)
+}
 
-            Err(Error::from_reason(""))
+#[inline]
+pub(crate) fn to_i64_with(
+    env: &Env,
+    value: Either<&Int64, Unknown>,
+    options: Option<InputOptions>,
+) -> Result<i64> {
+    match value {
+        Either::A(int64) => Ok(int64.v),
+        Either::B(value) => to_integer(env, value, is_strict(options)),
+    }
+}
+
+#[inline]
+pub(crate) fn to_u64(env: &Env, value: Either<&UInt64, Unknown>) -> Result<u64> {
+    to_u64_with(env, value, None)
+}
+
+#[inline]
+pub(crate) fn to_u64_with(
+    env: &Env,
+    value: Either<&UInt64, Unknown>,
+    options: Option<InputOptions>,
+) -> Result<u64> {
+    match value {
+        Either::A(uint64) => Ok(uint64.v),
+        Either::B(value) => to_integer(env, value, is_strict(options)),
+    }
+}
+
+#[inline]
+pub(crate) fn to_i128(env: &Env, value: Either3<&Int128, &Int64, Unknown>) -> Result<i128> {
+    to_i128_with(env, value, None)
+}
+
+#[inline]
+pub(crate) fn to_i128_with(
+    env: &Env,
+    value: Either3<&Int128, &Int64, Unknown>,
+    options: Option<InputOptions>,
+) -> Result<i128> {
+    match value {
+        Either3::A(int128) => Ok(int128.v),
+        Either3::B(int64) => Ok(int64.v as i128),
+        Either3::C(value) => to_integer(env, value, is_strict(options)),
+    }
+}
+
+#[inline]
+pub(crate) fn to_u128(env: &Env, value: Either3<&UInt128, &UInt64, Unknown>) -> Result<u128> {
+    to_u128_with(env, value, None)
+}
+
+#[inline]
+pub(crate) fn to_u128_with(
+    env: &Env,
+    value: Either3<&UInt128, &UInt64, Unknown>,
+    options: Option<InputOptions>,
+) -> Result<u128> {
+    match value {
+        Either3::A(uint128) => Ok(uint128.v),
+        Either3::B(uint64) => Ok(uint64.v as u128),
+        Either3::C(value) => to_integer(env, value, is_strict(options)),
+    }
+}
+
+/// Checks whether `scale` is between 0 and 18.
+pub(crate) fn check_scale(env: &Env, scale: u32) -> Result<u32> {
+    if scale > MAX_DECIMAL_SCALE {
+        env.throw_range_error(
+            &format!("the scale must be between 0 and {MAX_DECIMAL_SCALE}, but it is {scale}"),
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    }
+
+    Ok(scale)
+}
+
+/// Converts a number to a decimal number exactly by its shortest decimal representation, which is what JavaScript prints, so `0.1` becomes `0.1` rather than its binary approximation. Under the strict input policy, the numbers beyond the safe integer range are rejected.
+fn number_to_decimal(v: f64, strict: bool) -> std::result::Result<(i64, u32), Int64Error> {
+    if !v.is_finite() {
+        return Err(Int64Error::new(
+            ErrorCode::UnsafeNumber,
+            "a non-finite number is not a decimal number".to_string(),
+        ));
+    }
+
+    if strict && v.abs() > MAX_SAFE_INTEGER as f64 {
+        return Err(Int64Error::new(
+            ErrorCode::UnsafeNumber,
+            format!(
+                "{v} is beyond the safe integer range, use a string, a bigint, a buffer or an \
+                 instance instead"
+            ),
+        ));
+    }
+
+    // `Display` of `f64` prints the shortest digits that round-trip, without an exponent.
+    parse_decimal(&v.to_string()).map_err(|_| {
+        Int64Error::new(
+            ErrorCode::OutOfRange,
+            format!(
+                "{v} cannot be represented by a decimal number with a 64-bit unscaled value and \
+                 at most {MAX_DECIMAL_SCALE} fractional digits"
+            ),
+        )
+    })
+}
+
+/// Converts a value to the unscaled value and the scale of a decimal number. Strings and numbers are converted as decimal numbers exactly, and the other values are integers whose scale is 0.
+pub(crate) fn to_decimal64(
+    env: &Env,
+    value: Either3<&Decimal64, &Int64, Unknown>,
+) -> Result<(i64, u32)> {
+    to_decimal64_with(env, value, None)
+}
+
+#[inline]
+pub(crate) fn to_decimal64_with(
+    env: &Env,
+    value: Either3<&Decimal64, &Int64, Unknown>,
+    options: Option<InputOptions>,
+) -> Result<(i64, u32)> {
+    match value {
+        Either3::A(decimal64) => Ok((decimal64.v, decimal64.scale)),
+        Either3::B(int64) => Ok((int64.v, 0)),
+        Either3::C(value) => {
+            let input = unsafe { value.raw() };
+
+            let decimal = match value.get_type()? {
+                ValueType::String => {
+                    parse_decimal(value.coerce_to_string()?.into_utf8()?.as_str()?)
+                },
+                ValueType::Number => {
+                    number_to_decimal(value.coerce_to_number()?.get_double()?, is_strict(options))
+                },
+                _ => return Ok((to_integer(env, value, is_strict(options))?, 0)),
+            };
+
+            match decimal {
+                Ok(decimal) => Ok(decimal),
+                Err(error) => {
+                    error.throw(env, Some(input))?;
+
+                    Err(Error::from_reason(""))
+                },
+            }
         },
     }
 }
+
+/// Checks whether the number of fractional bits of a fixed-point number is between 0 and 63.
+pub(crate) fn check_frac_bits(env: &Env, frac_bits: u32) -> Result<u32> {
+    if frac_bits > 63 {
+        env.throw_range_error(
+            &format!(
+                "the number of fractional bits must be between 0 and 63, but it is {frac_bits}"
+            ),
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    }
+
+    Ok(frac_bits)
+}
+
+fn throw_bad_length<T>(env: &Env, message: String) -> Result<T> {
+    Int64Error::new(ErrorCode::BadBufferLength, message).throw(env, None)?;
+
+    Err(Error::from_reason(""))
+}
+
+fn throw_unsupported_typed_array<T>(env: &Env, value: &Unknown) -> Result<T> {
+    Int64Error::new(
+        ErrorCode::UnsupportedType,
+        "the typed array is neither a BigInt64Array nor a buffer",
+    )
+    .throw(env, Some(unsafe { value.raw() }))?;
+
+    Err(Error::from_reason(""))
+}
+
+/// Reads the 64-bit integers of a `BigInt64Array`, a buffer of packed little-endian int64 values or an array. Returns `None` if the value is none of them.
+fn read_i64_array(env: &Env, value: &Unknown) -> Result<Option<Vec<i64>>> {
+    // buffers are `Uint8Array`s, and `napi_is_buffer` also accepts the other typed arrays, so check the type of the typed array instead
+    if value.is_typedarray()? {
+        let typed_array = unsafe { value.cast::<JsTypedArray>() }.into_value()?;
+
+        match typed_array.typedarray_type {
+            TypedArrayType::BigInt64 => Ok(Some(AsRef::<[i64]>::as_ref(&typed_array).to_vec())),
+            TypedArrayType::Uint8 => {
+                let data = AsRef::<[u8]>::as_ref(&typed_array);
+
+                if data.len() % 8 != 0 {
+                    return throw_bad_length(
+                        env,
+                        format!(
+                            "the length of the input buffer is not a multiple of 8, but it is {}",
+                            data.len()
+                        ),
+                    );
+                }
+
+                Ok(Some(
+                    data.chunks_exact(8)
+                        .map(|chunk| i64::from_le_bytes(chunk.try_into().unwrap()))
+                        .collect(),
+                ))
+            },
+            _ => throw_unsupported_typed_array(env, value),
+        }
+    } else if value.is_array()? {
+        let array = unsafe { Array::from_napi_value(env.raw(), value.raw())? };
+
+        (0..array.len())
+            .map(|i| {
+                let element: Either<&Int64, Unknown> = array.get(i)?.ok_or_else(|| {
+                    Error::new(Status::InvalidArg, format!("the array has no element at {i}"))
+                })?;
+
+                to_i64(env, element)
+            })
+            .collect::<Result<Vec<i64>>>()
+            .map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+/// Computes `op(a[i], b[i])` (or `op(a[i], b)` if `b` is a scalar) for every element, and writes the results into `output`.
+pub(crate) fn apply_bulk(
+    env: &Env,
+    a: Unknown,
+    b: Either<&Int64, Unknown>,
+    output: Unknown,
+    op: impl Fn(i64, i64) -> i64,
+) -> Result<()> {
+    let a = match read_i64_array(env, &a)? {
+        Some(a) => a,
+        None => {
+            Int64Error::new(
+                ErrorCode::UnsupportedType,
+                "the operand is neither a BigInt64Array, a buffer nor an array",
+            )
+            .throw(env, Some(unsafe { a.raw() }))?;
+
+            return Err(Error::from_reason(""));
+        },
+    };
+
+    let b_array = match &b {
+        Either::A(_) => None,
+        Either::B(b) => read_i64_array(env, b)?,
+    };
+
+    // the results are computed before writing, so the output can be one of the operands
+    let results: Vec<i64> = match b_array {
+        Some(b) => {
+            if a.len() != b.len() {
+                return throw_bad_length(
+                    env,
+                    format!(
+                        "the lengths of the operands do not match: {} and {}",
+                        a.len(),
+                        b.len()
+                    ),
+                );
+            }
+
+            a.iter().zip(b.iter()).map(|(&a, &b)| op(a, b)).collect()
+        },
+        None => {
+            let b = to_i64(env, b)?;
+
+            a.iter().map(|&a| op(a, b)).collect()
+        },
+    };
+
+    if output.is_typedarray()? {
+        let mut typed_array = unsafe { output.cast::<JsTypedArray>() }.into_value()?;
+
+        match typed_array.typedarray_type {
+            TypedArrayType::BigInt64 => {
+                let data = AsMut::<[i64]>::as_mut(&mut typed_array);
+
+                if data.len() != results.len() {
+                    return throw_bad_length(
+                        env,
+                        format!("the length of the output array is not {}", results.len()),
+                    );
+                }
+
+                data.copy_from_slice(&results);
+            },
+            TypedArrayType::Uint8 => {
+                let data = AsMut::<[u8]>::as_mut(&mut typed_array);
+
+                if data.len() != results.len() * 8 {
+                    return throw_bad_length(
+                        env,
+                        format!("the length of the output buffer is not {}", results.len() * 8),
+                    );
+                }
+
+                for (chunk, v) in data.chunks_exact_mut(8).zip(results) {
+                    chunk.copy_from_slice(&v.to_le_bytes());
+                }
+            },
+            _ => return throw_unsupported_typed_array(env, &output),
+        }
+    } else if output.is_array()? {
+        let mut array = unsafe { Array::from_napi_value(env.raw(), output.raw())? };
+
+        if array.len() as usize != results.len() {
+            return throw_bad_length(
+                env,
+                format!("the length of the output array is not {}", results.len()),
+            );
+        }
+
+        // the `Int64` elements are changed in place like the other methods do, and the others are replaced with new `Int64` objects
+        for (i, v) in results.into_iter().enumerate() {
+            let element: Option<Either<&mut Int64, Unknown>> = array.get(i as u32)?;
+
+            match element {
+                Some(Either::A(int64)) => int64.v = v,
+                _ => array.set(i as u32, Int64 {
+                    v,
+                })?,
+            }
+        }
+    } else {
+        Int64Error::new(
+            ErrorCode::UnsupportedType,
+            "the output is neither a BigInt64Array, a buffer nor an array",
+        )
+        .throw(env, Some(unsafe { output.raw() }))?;
+
+        return Err(Error::from_reason(""));
+    }
+
+    Ok(())
+}
@@ -1,10 +1,28 @@
+mod decimal64;
+mod errors;
+#[macro_use]
 mod functions;
+mod fixed64;
+mod int128;
 mod napi_static;
+pub mod napi_static_bigint;
+pub mod napi_static_bulk;
+pub mod napi_static_uint64;
+mod uint128;
+mod uint64;
 
+use std::cell::Cell;
+
+pub use decimal64::*;
+pub use errors::*;
+pub use fixed64::*;
 use functions::*;
-use napi::bindgen_prelude::*;
+pub use int128::*;
+use napi::{bindgen_prelude::*, JsFunction, JsUnknown};
 use napi_derive::napi;
 pub use napi_static::*;
+pub use uint128::*;
+pub use uint64::*;
 
 // 2^53 - 1;
 const MAX_SAFE_INTEGER: i64 = 9007199254740991;
@@ -12,6 +30,42 @@ const MAX_SAFE_INTEGER: i64 = 9007199254740991;
 // -(2^53 - 1);
 const MIN_SAFE_INTEGER: i64 = -9007199254740991;
 
+// The maximum scale of a `Decimal64`, which keeps `10^scale` in an int64 (long) value.
+const MAX_DECIMAL_SCALE: u32 = 18;
+
+thread_local! {
+    static STRICT_INPUT: Cell<bool> = const { Cell::new(true) };
+}
+
+/// Checks whether the strict input policy is enabled. Under this policy, which is enabled by default, a number input must be a safe integer, otherwise a TypeError is thrown. Pass a string, a bigint, a buffer or an instance for the integers out of the safe range.
+#[napi]
+pub fn is_strict_input() -> bool {
+    STRICT_INPUT.with(Cell::get)
+}
+
+/// Enables or disables the strict input policy. When it is disabled, a number input is converted as `Int64.fromNumber` does by default: it is truncated towards zero, NaN becomes `0`, and the numbers out of the range of an int64 (long) value are clamped.
+#[napi]
+pub fn set_strict_input(strict: bool) {
+    STRICT_INPUT.with(|s| s.set(strict))
+}
+
+/// Calls `callback` with the strict input policy enabled or disabled, and restores the previous policy afterwards.
+///
+/// The policy is restored as soon as `callback` returns, so it does not cover the code of an async callback after its first `await`. Use the `strict` option of the constructors and the factories in that case.
+#[napi(ts_generic_types = "T", ts_return_type = "T")]
+pub fn with_strict_input(
+    strict: bool,
+    #[napi(ts_arg_type = "() => T")] callback: JsFunction,
+) -> Result<JsUnknown> {
+    let previous = STRICT_INPUT.with(|s| s.replace(strict));
+
+    let result = callback.call_without_args(None);
+
+    set_strict_input(previous);
+
+    result
+}
+
 // We need this enum to build the definition of Ordering.
 #[napi]
 pub enum Ordering {
@@ -20,6 +74,26 @@ pub enum Ordering {
     Greater = 1,
 }
 
+/// The byte order of a buffer.
+#[napi(string_enum)]
+pub enum Endianness {
+    /// Little-Endian.
+    #[napi(value = "LE")]
+    Little,
+    /// Big-Endian.
+    #[napi(value = "BE")]
+    Big,
+}
+
+/// The options for parsing a string as an integer number.
+#[napi(object)]
+pub struct ParseOptions {
+    /// An integer between 2 and 36. If it is not set, the radix is decided by the `0b`, `0o` or `0x` prefix of the string, or is 10 without any prefix.
+    pub radix:   Option<u32>,
+    /// Allows surrounding whitespace, a `+` or `-` sign in front of the radix prefix and `_` between digits. Default: `false`.
+    pub lenient: Option<bool>,
+}
+
 impl From<std::cmp::Ordering> for Ordering {
     #[inline]
     fn from(value: std::cmp::Ordering) -> Self {
@@ -31,6 +105,129 @@ impl From<std::cmp::Ordering> for Ordering {
     }
 }
 
+/// The 32-bit words of a 64-bit integer, like a `Long` object of Long.js.
+#[napi(object)]
+pub struct LongBits {
+    /// The low 32 bits, as a signed 32-bit integer.
+    pub low:      i32,
+    /// The high 32 bits, as a signed 32-bit integer.
+    pub high:     i32,
+    /// Whether the 64-bit integer is unsigned. Default: `false`.
+    pub unsigned: Option<bool>,
+}
+
+/// How a number is rounded to an integer.
+#[napi(string_enum)]
+pub enum NumberRounding {
+    /// Rounds towards zero.
+    #[napi(value = "trunc")]
+    Trunc,
+    /// Rounds towards negative infinity.
+    #[napi(value = "floor")]
+    Floor,
+    /// Rounds towards positive infinity.
+    #[napi(value = "ceil")]
+    Ceil,
+    /// Rounds to the nearest integer, and rounds half-way cases away from zero.
+    #[napi(value = "round")]
+    Round,
+    /// Throws a RangeError for NaN, infinities, non-integers and the numbers out of range.
+    #[napi(value = "strict")]
+    Strict,
+}
+
+/// The options of converting an input value.
+#[napi(object)]
+pub struct InputOptions {
+    /// Whether a number input must be a safe integer. Default: the strict input policy, see `setStrictInput`.
+    pub strict: Option<bool>,
+}
+
+/// The options for converting a number to an integer.
+#[napi(object)]
+pub struct FromNumberOptions {
+    /// How the number is rounded. In the modes except `"strict"`, NaN becomes `0` and the numbers out of range are clamped. Default: `"trunc"`.
+    pub rounding: Option<NumberRounding>,
+}
+
+/// How the bit count of a shift or a rotation is treated when it is not smaller than 64.
+#[napi(string_enum)]
+pub enum ShiftMode {
+    /// Uses the bit count modulo 64, like the shift operators of JavaScript.
+    #[napi(value = "mask")]
+    Mask,
+    /// Throws a RangeError.
+    #[napi(value = "strict")]
+    Strict,
+    /// Shifts out all bits, so the result is `0`, or `-1` for the arithmetic right shift of a negative number. Rotations use the bit count modulo 64.
+    #[napi(value = "saturate")]
+    Saturate,
+}
+
+/// How the result of a fixed-point arithmetic operation is treated when it overflows.
+#[napi(string_enum)]
+pub enum OverflowMode {
+    /// Wraps around at the boundary, like the arithmetic of `Int64`.
+    #[napi(value = "wrap")]
+    Wrap,
+    /// Clamps the result to the minimum or the maximum value.
+    #[napi(value = "saturate")]
+    Saturate,
+}
+
+/// How the quotient of a division is rounded.
+#[napi(string_enum)]
+pub enum DivisionRounding {
+    /// Rounds towards zero, like the `/` operator.
+    #[napi(value = "trunc")]
+    Trunc,
+    /// Rounds towards negative infinity.
+    #[napi(value = "floor")]
+    Floor,
+    /// Rounds towards positive infinity.
+    #[napi(value = "ceil")]
+    Ceil,
+    /// Rounds so that the remainder is never negative.
+    #[napi(value = "euclid")]
+    Euclid,
+}
+
+/// How a decimal number is rounded when fractional digits are dropped.
+#[napi(string_enum)]
+pub enum DecimalRounding {
+    /// Rounds to the nearest value, and rounds half-way cases to the even neighbor, also known as banker's rounding.
+    #[napi(value = "halfEven")]
+    HalfEven,
+    /// Rounds to the nearest value, and rounds half-way cases away from zero.
+    #[napi(value = "halfUp")]
+    HalfUp,
+    /// Rounds towards negative infinity.
+    #[napi(value = "floor")]
+    Floor,
+    /// Rounds towards positive infinity.
+    #[napi(value = "ceil")]
+    Ceil,
+    /// Rounds towards zero.
+    #[napi(value = "trunc")]
+    Trunc,
+}
+
+/// The quotient and the remainder of a division.
+#[napi(object, object_from_js = false)]
+pub struct DivModResult {
+    pub quotient:  Int64,
+    pub remainder: Int64,
+}
+
+/// The result of an overflowing operation.
+#[napi(object, object_from_js = false)]
+pub struct OverflowingResult {
+    /// The result, wrapped around at the boundary of an 64-bit integer.
+    pub value:      Int64,
+    /// Whether an arithmetic overflow occurred.
+    pub overflowed: bool,
+}
+
 #[napi]
 pub struct Int64 {
     v: i64,
@@ -39,16 +236,18 @@ pub struct Int64 {
 #[napi]
 impl Int64 {
     /// @param value The initial value. Default: `0`.
+    /// @param options The options of converting `value`.
     #[napi(constructor)]
     pub fn new(
         env: Env,
-        #[napi(ts_arg_type = "number | string | Buffer | Int64")] value: Option<
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Option<
             Either<&Int64, Unknown>,
         >,
+        options: Option<InputOptions>,
     ) -> Result<Self> {
         match value {
             Some(value) => {
-                let v = to_i64(&env, value)?;
+                let v = to_i64_with(&env, value, options)?;
 
                 Ok(Int64 {
                     v,
@@ -60,6 +259,65 @@ impl Int64 {
         }
     }
 
+    /// Parses a string as a 64-bit integer.
+    ///
+    /// @param options A radix between 2 and 36, or the parsing options. If the radix is not set, it is decided by the `0b`, `0o` or `0x` prefix of the string, or is 10 without any prefix.
+    #[napi(factory)]
+    pub fn parse(
+        env: Env,
+        value: String,
+        #[napi(ts_arg_type = "number | ParseOptions")] options: Option<Either<u32, ParseOptions>>,
+    ) -> Result<Self> {
+        let v = parse_string(&env, value, options)?;
+
+        Ok(Int64 {
+            v,
+        })
+    }
+
+    /// Creates a 64-bit integer from its low and high 32 bits, like `Long.fromBits` of Long.js.
+    #[napi(factory, js_name = "fromBits")]
+    pub fn from_bits(low: i32, high: i32) -> Self {
+        Int64 {
+            v: ((high as u32 as u64) << 32 | low as u32 as u64) as i64
+        }
+    }
+
+    /// Converts a number to a 64-bit integer with explicit rounding.
+    #[napi(factory, js_name = "fromNumber")]
+    pub fn from_number(env: Env, value: f64, options: Option<FromNumberOptions>) -> Result<Self> {
+        let rounding =
+            options.and_then(|options| options.rounding).unwrap_or(NumberRounding::Trunc);
+
+        let v = number_to_i64(&env, value, rounding)?;
+
+        Ok(Int64 {
+            v,
+        })
+    }
+
+    /// Reads a 64-bit integer from `buffer` at `offset`.
+    ///
+    /// @param offset The position where to start reading. Default: `0`.
+    /// @param endianness The byte order. Default: `"LE"`.
+    #[napi(factory, js_name = "fromBuffer")]
+    pub fn from_buffer(
+        env: Env,
+        buffer: Buffer,
+        offset: Option<u32>,
+        endianness: Option<Endianness>,
+    ) -> Result<Self> {
+        let offset = check_buffer_bounds(&env, buffer.len(), offset, 8)?;
+
+        let mut bytes = [0u8; 8];
+
+        bytes.copy_from_slice(&buffer[offset..offset + 8]);
+
+        Ok(Int64 {
+            v: from_bytes!(i64, bytes, endianness)
+        })
+    }
+
     /// To a decimal number in a string.
     #[napi(js_name = "toDecimal")]
     pub fn to_decimal(&self) -> String {
@@ -67,10 +325,17 @@ impl Int64 {
     }
 
     #[allow(clippy::inherent_to_string)]
-    /// To a decimal number in a string.
+    /// To a number in a string, like `Number.prototype.toString`. Negative numbers are formatted with a minus sign rather than in two's complement.
+    ///
+    /// @param radix An integer between 2 and 36. Default: `10`.
     #[napi(js_name = "toString")]
-    pub fn to_string(&self) -> String {
-        self.to_decimal()
+    pub fn to_string(&self, env: Env, radix: Option<u32>) -> Result<String> {
+        match check_radix(&env, radix)? {
+            Some(radix) if radix != 10 => {
+                Ok(to_string_radix(self.v.unsigned_abs() as u128, radix, self.v < 0))
+            },
+            _ => Ok(self.to_decimal()),
+        }
     }
 
     /// To a binary number in a string.
@@ -109,10 +374,31 @@ impl Int64 {
         }
     }
 
-    /// To a 64-bit buffer in Little-Endian byte order.
+    /// To a 64-bit buffer.
+    ///
+    /// @param endianness The byte order. Default: `"LE"`.
     #[napi(js_name = "toBuffer")]
-    pub fn to_buffer(&self) -> Buffer {
-        Buffer::from(self.v.to_le_bytes().to_vec())
+    pub fn to_buffer(&self, endianness: Option<Endianness>) -> Buffer {
+        Buffer::from(to_bytes!(self.v, endianness).to_vec())
+    }
+
+    /// Writes this 64-bit integer into `buffer` at `offset` in place, and returns `offset` plus the number of bytes written.
+    ///
+    /// @param offset The position where to start writing. Default: `0`.
+    /// @param endianness The byte order. Default: `"LE"`.
+    #[napi(js_name = "writeToBuffer")]
+    pub fn write_to_buffer(
+        &self,
+        env: Env,
+        mut buffer: Buffer,
+        offset: Option<u32>,
+        endianness: Option<Endianness>,
+    ) -> Result<u32> {
+        let offset = check_buffer_bounds(&env, buffer.len(), offset, 8)?;
+
+        buffer[offset..offset + 8].copy_from_slice(&to_bytes!(self.v, endianness));
+
+        Ok((offset + 8) as u32)
     }
 
     /// To a number. If this 64-bit integer number is bigger than `2^53 - 1`, or smaller than `-(2^53 - 1)`, then throws a RangeError.
@@ -121,14 +407,14 @@ impl Int64 {
         if self.v > MAX_SAFE_INTEGER {
             env.throw_range_error(
                 format!("{} is bigger than {MAX_SAFE_INTEGER}", self.v).as_str(),
-                None,
+                Some(ErrorCode::OutOfRange.as_str()),
             )?;
 
             Err(Error::from_reason(""))
         } else if self.v < MIN_SAFE_INTEGER {
             env.throw_range_error(
                 format!("{} is smaller than {MIN_SAFE_INTEGER}", self.v).as_str(),
-                None,
+                Some(ErrorCode::OutOfRange.as_str()),
             )?;
 
             Err(Error::from_reason(""))
@@ -137,13 +423,68 @@ impl Int64 {
         }
     }
 
+    /// To the nearest number. It is lossy if this 64-bit integer number is not a safe integer.
+    #[napi(js_name = "toNumberLossy")]
+    pub fn to_number_lossy(&self) -> f64 {
+        self.v as f64
+    }
+
+    /// To a number. If this 64-bit integer number is bigger than `2^53 - 1`, or smaller than `-(2^53 - 1)`, then returns `null`.
+    #[napi(js_name = "toNumberOrNull")]
+    pub fn to_number_or_null(&self) -> Option<i64> {
+        if self.is_safe_integer() {
+            Some(self.v)
+        } else {
+            None
+        }
+    }
+
+    /// Tests whether this 64-bit integer number is between `-(2^53 - 1)` and `2^53 - 1`, which means it can be converted to a number losslessly.
+    #[napi(js_name = "isSafeInteger")]
+    pub fn is_safe_integer(&self) -> bool {
+        (MIN_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(&self.v)
+    }
+
+    /// To a bigint.
+    #[napi(js_name = "toBigInt")]
+    pub fn to_bigint(&self) -> BigInt {
+        BigInt::from(self.v)
+    }
+
+    /// Gets the high 32 bits as a signed 32-bit integer.
+    #[napi(js_name = "getHighBits")]
+    pub fn get_high_bits(&self) -> i32 {
+        (self.v >> 32) as i32
+    }
+
+    /// Gets the low 32 bits as a signed 32-bit integer.
+    #[napi(js_name = "getLowBits")]
+    pub fn get_low_bits(&self) -> i32 {
+        self.v as i32
+    }
+
+    /// Gets the high 32 bits as an unsigned 32-bit integer.
+    #[napi(js_name = "getHighBitsUnsigned")]
+    pub fn get_high_bits_unsigned(&self) -> u32 {
+        (self.v >> 32) as u32
+    }
+
+    /// Gets the low 32 bits as an unsigned 32-bit integer.
+    #[napi(js_name = "getLowBitsUnsigned")]
+    pub fn get_low_bits_unsigned(&self) -> u32 {
+        self.v as u32
+    }
+
     /// Sets the value of this instance.
     #[napi]
     pub fn set(
         &mut self,
         this: This,
         env: Env,
-        #[napi(ts_arg_type = "number | string | Buffer | Int64")] value: Either<&Int64, Unknown>,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
     ) -> Result<This> {
         let v = to_i64(&env, value)?;
 
@@ -158,7 +499,10 @@ impl Int64 {
         &mut self,
         this: This,
         env: Env,
-        #[napi(ts_arg_type = "number | string | Buffer | Int64")] value: Either<&Int64, Unknown>,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
     ) -> Result<This> {
         let a = self.v;
         let b = to_i64(&env, value)?;
@@ -174,7 +518,10 @@ impl Int64 {
         &mut self,
         this: This,
         env: Env,
-        #[napi(ts_arg_type = "number | string | Buffer | Int64")] value: Either<&Int64, Unknown>,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
     ) -> Result<This> {
         let a = self.v;
         let b = to_i64(&env, value)?;
@@ -190,7 +537,10 @@ impl Int64 {
         &mut self,
         this: This,
         env: Env,
-        #[napi(ts_arg_type = "number | string | Buffer | Int64")] value: Either<&Int64, Unknown>,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
     ) -> Result<This> {
         let a = self.v;
         let b = to_i64(&env, value)?;
@@ -206,11 +556,26 @@ impl Int64 {
         &mut self,
         this: This,
         env: Env,
-        #[napi(ts_arg_type = "number | string | Buffer | Int64")] value: Either<&Int64, Unknown>,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
     ) -> Result<This> {
         let a = self.v;
+        let input = value.raw_input();
         let b = to_i64(&env, value)?;
 
+        if b == 0 {
+            return throw_divide_by_zero(
+                &env,
+                format!("attempt to divide {a} by zero"),
+                input,
+                Int64 {
+                    v: b
+                },
+            );
+        }
+
         self.v = a.wrapping_div(b);
 
         Ok(this)
@@ -222,16 +587,226 @@ impl Int64 {
         &mut self,
         this: This,
         env: Env,
-        #[napi(ts_arg_type = "number | string | Buffer | Int64")] value: Either<&Int64, Unknown>,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
     ) -> Result<This> {
         let a = self.v;
+        let input = value.raw_input();
         let b = to_i64(&env, value)?;
 
+        if b == 0 {
+            return throw_divide_by_zero(
+                &env,
+                format!("attempt to calculate the remainder of {a} with a divisor of zero"),
+                input,
+                Int64 {
+                    v: b
+                },
+            );
+        }
+
         self.v = a.wrapping_rem(b);
 
         Ok(this)
     }
 
+    /// Computes the Euclidean quotient of `self / value`, wrapping around at the boundary of an 64-bit integer. The remainder of the Euclidean division is never negative.
+    #[napi(js_name = "divEuclid")]
+    pub fn div_euclid(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        let a = self.v;
+        let input = value.raw_input();
+        let b = to_i64(&env, value)?;
+
+        if b == 0 {
+            return throw_divide_by_zero(
+                &env,
+                format!("attempt to divide {a} by zero"),
+                input,
+                Int64 {
+                    v: b
+                },
+            );
+        }
+
+        self.v = div_rem(a, b, DivisionRounding::Euclid).0;
+
+        Ok(this)
+    }
+
+    /// Computes the least non-negative remainder of `self / value`, wrapping around at the boundary of an 64-bit integer.
+    #[napi(js_name = "remEuclid")]
+    pub fn rem_euclid(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        let a = self.v;
+        let input = value.raw_input();
+        let b = to_i64(&env, value)?;
+
+        if b == 0 {
+            return throw_divide_by_zero(
+                &env,
+                format!("attempt to calculate the remainder of {a} with a divisor of zero"),
+                input,
+                Int64 {
+                    v: b
+                },
+            );
+        }
+
+        self.v = div_rem(a, b, DivisionRounding::Euclid).1;
+
+        Ok(this)
+    }
+
+    /// Computes the quotient of `self / value` rounded towards negative infinity, wrapping around at the boundary of an 64-bit integer.
+    #[napi(js_name = "divFloor")]
+    pub fn div_floor(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        let a = self.v;
+        let input = value.raw_input();
+        let b = to_i64(&env, value)?;
+
+        if b == 0 {
+            return throw_divide_by_zero(
+                &env,
+                format!("attempt to divide {a} by zero"),
+                input,
+                Int64 {
+                    v: b
+                },
+            );
+        }
+
+        self.v = div_rem(a, b, DivisionRounding::Floor).0;
+
+        Ok(this)
+    }
+
+    /// Computes the remainder of `self / value` with the quotient rounded towards negative infinity, wrapping around at the boundary of an 64-bit integer. The remainder has the same sign as `value`.
+    #[napi(js_name = "modFloor")]
+    pub fn mod_floor(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        let a = self.v;
+        let input = value.raw_input();
+        let b = to_i64(&env, value)?;
+
+        if b == 0 {
+            return throw_divide_by_zero(
+                &env,
+                format!("attempt to calculate the remainder of {a} with a divisor of zero"),
+                input,
+                Int64 {
+                    v: b
+                },
+            );
+        }
+
+        self.v = div_rem(a, b, DivisionRounding::Floor).1;
+
+        Ok(this)
+    }
+
+    /// Computes the quotient of `self / value` rounded towards positive infinity, wrapping around at the boundary of an 64-bit integer.
+    #[napi(js_name = "divCeil")]
+    pub fn div_ceil(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        let a = self.v;
+        let input = value.raw_input();
+        let b = to_i64(&env, value)?;
+
+        if b == 0 {
+            return throw_divide_by_zero(
+                &env,
+                format!("attempt to divide {a} by zero"),
+                input,
+                Int64 {
+                    v: b
+                },
+            );
+        }
+
+        self.v = div_rem(a, b, DivisionRounding::Ceil).0;
+
+        Ok(this)
+    }
+
+    /// Computes the quotient and the remainder of `self / value` together, wrapping around at the boundary of an 64-bit integer. This instance is not changed.
+    ///
+    /// @param rounding How the quotient is rounded. Default: `"trunc"`.
+    #[napi(js_name = "divMod")]
+    pub fn div_mod(
+        &self,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+        rounding: Option<DivisionRounding>,
+    ) -> Result<DivModResult> {
+        let a = self.v;
+        let input = value.raw_input();
+        let b = to_i64(&env, value)?;
+
+        if b == 0 {
+            return throw_divide_by_zero(
+                &env,
+                format!("attempt to divide {a} by zero"),
+                input,
+                Int64 {
+                    v: b
+                },
+            );
+        }
+
+        let (quotient, remainder) = div_rem(a, b, rounding.unwrap_or(DivisionRounding::Trunc));
+
+        Ok(DivModResult {
+            quotient:  Int64 {
+                v: quotient
+            },
+            remainder: Int64 {
+                v: remainder
+            },
+        })
+    }
+
     /// Computes `self %= value`, wrapping around at the boundary of an 64-bit integer.
     ///
     /// `b` must not be smaller than zero
@@ -240,7 +815,10 @@ impl Int64 {
         &mut self,
         this: This,
         env: Env,
-        #[napi(ts_arg_type = "number | string | Buffer | Int64")] value: Either<&Int64, Unknown>,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
     ) -> Result<This> {
         let a = self.v;
         let b = to_i64(&env, value)?;
@@ -248,14 +826,14 @@ impl Int64 {
         if b < 0 {
             env.throw_range_error(
                 "the exponent of an integer number must not be smaller than zero",
-                None,
+                Some(ErrorCode::OutOfRange.as_str()),
             )?;
 
             return Err(Error::from_reason(""));
         } else if b > u32::MAX as i64 {
             env.throw_range_error(
                 &format!("the exponent of an integer number must not be bigger than {}", u32::MAX),
-                None,
+                Some(ErrorCode::OutOfRange.as_str()),
             )?;
 
             return Err(Error::from_reason(""));
@@ -269,30 +847,41 @@ impl Int64 {
     /// Computes `self <<= value`, wrapping around at the boundary of an 64-bit integer.
     ///
     /// `b` must not be smaller than zero
+    ///
+    /// @param mode How a bit count which is not smaller than 64 is treated. Default: `"mask"`.
     #[napi(js_name = "shiftLeft")]
     pub fn shift_left(
         &mut self,
         this: This,
         env: Env,
-        #[napi(ts_arg_type = "number | string | Buffer | Int64")] value: Either<&Int64, Unknown>,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+        mode: Option<ShiftMode>,
     ) -> Result<This> {
         let a = self.v;
         let b = to_i64(&env, value)?;
 
         if b < 0 {
-            env.throw_range_error("the bit count for shift must not be smaller than zero", None)?;
+            env.throw_range_error(
+                "the bit count for shift must not be smaller than zero",
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
 
             return Err(Error::from_reason(""));
         } else if b > u32::MAX as i64 {
             env.throw_range_error(
                 &format!("the bit count for shift must not be bigger than {}", u32::MAX),
-                None,
+                Some(ErrorCode::OutOfRange.as_str()),
             )?;
 
             return Err(Error::from_reason(""));
         }
 
-        self.v = a.wrapping_shl(b as u32);
+        let b = check_shift_count(&env, b as u32, mode, "shift")?;
+
+        self.v = shl_i64(a, b, mode);
 
         Ok(this)
     }
@@ -300,30 +889,41 @@ impl Int64 {
     /// Computes `self >>= value`, wrapping around at the boundary of an 64-bit integer.
     ///
     /// `b` must not be smaller than zero
+    ///
+    /// @param mode How a bit count which is not smaller than 64 is treated. Default: `"mask"`.
     #[napi(js_name = "shiftRight")]
     pub fn shift_right(
         &mut self,
         this: This,
         env: Env,
-        #[napi(ts_arg_type = "number | string | Buffer | Int64")] value: Either<&Int64, Unknown>,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+        mode: Option<ShiftMode>,
     ) -> Result<This> {
         let a = self.v;
         let b = to_i64(&env, value)?;
 
         if b < 0 {
-            env.throw_range_error("the bit count for shift must not be smaller than zero", None)?;
+            env.throw_range_error(
+                "the bit count for shift must not be smaller than zero",
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
 
             return Err(Error::from_reason(""));
         } else if b > u32::MAX as i64 {
             env.throw_range_error(
                 &format!("the bit count for shift must not be bigger than {}", u32::MAX),
-                None,
+                Some(ErrorCode::OutOfRange.as_str()),
             )?;
 
             return Err(Error::from_reason(""));
         }
 
-        self.v = a.wrapping_shr(b as u32);
+        let b = check_shift_count(&env, b as u32, mode, "shift")?;
+
+        self.v = shr_i64(a, b, mode);
 
         Ok(this)
     }
@@ -331,30 +931,41 @@ impl Int64 {
     /// Computes `self >>= value`, wrapping around at the boundary of an 64-bit integer.
     ///
     /// `b` must not be smaller than zero
+    ///
+    /// @param mode How a bit count which is not smaller than 64 is treated. Default: `"mask"`.
     #[napi(js_name = "shiftRightUnsigned")]
     pub fn shift_right_unsigned(
         &mut self,
         this: This,
         env: Env,
-        #[napi(ts_arg_type = "number | string | Buffer | Int64")] value: Either<&Int64, Unknown>,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+        mode: Option<ShiftMode>,
     ) -> Result<This> {
         let a = self.v;
         let b = to_i64(&env, value)?;
 
         if b < 0 {
-            env.throw_range_error("the bit count for shift must not be smaller than zero", None)?;
+            env.throw_range_error(
+                "the bit count for shift must not be smaller than zero",
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
 
             return Err(Error::from_reason(""));
         } else if b > u32::MAX as i64 {
             env.throw_range_error(
                 &format!("the bit count for shift must not be bigger than {}", u32::MAX),
-                None,
+                Some(ErrorCode::OutOfRange.as_str()),
             )?;
 
             return Err(Error::from_reason(""));
         }
 
-        self.v = (a as u64).wrapping_shr(b as u32) as i64;
+        let b = check_shift_count(&env, b as u32, mode, "shift")?;
+
+        self.v = shr_unsigned_i64(a, b, mode);
 
         Ok(this)
     }
@@ -362,12 +973,18 @@ impl Int64 {
     /// Shifts the bits to the left by a specified amount n, wrapping the truncated bits to the beginning of the resulting 64-bit integer.
     ///
     /// `b` must not be smaller than zero
+    ///
+    /// @param mode How a bit count which is not smaller than 64 is treated. Default: `"mask"`.
     #[napi(js_name = "rotateLeft")]
     pub fn rotate_left(
         &mut self,
         this: This,
         env: Env,
-        #[napi(ts_arg_type = "number | string | Buffer | Int64")] value: Either<&Int64, Unknown>,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+        mode: Option<ShiftMode>,
     ) -> Result<This> {
         let a = self.v;
         let b = to_i64(&env, value)?;
@@ -375,20 +992,22 @@ impl Int64 {
         if b < 0 {
             env.throw_range_error(
                 "the bit count for rotation must not be smaller than zero",
-                None,
+                Some(ErrorCode::OutOfRange.as_str()),
             )?;
 
             return Err(Error::from_reason(""));
         } else if b > u32::MAX as i64 {
             env.throw_range_error(
                 &format!("the bit count for rotation must not be bigger than {}", u32::MAX),
-                None,
+                Some(ErrorCode::OutOfRange.as_str()),
             )?;
 
             return Err(Error::from_reason(""));
         }
 
-        self.v = a.rotate_left(b as u32);
+        let b = check_shift_count(&env, b as u32, mode, "rotation")?;
+
+        self.v = a.rotate_left(b);
 
         Ok(this)
     }
@@ -396,12 +1015,18 @@ impl Int64 {
     /// Shifts the bits to the right by a specified amount n, wrapping the truncated bits to the beginning of the resulting 64-bit integer.
     ///
     /// `b` must not be smaller than zero
+    ///
+    /// @param mode How a bit count which is not smaller than 64 is treated. Default: `"mask"`.
     #[napi(js_name = "rotateRight")]
     pub fn rotate_right(
         &mut self,
         this: This,
         env: Env,
-        #[napi(ts_arg_type = "number | string | Buffer | Int64")] value: Either<&Int64, Unknown>,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+        mode: Option<ShiftMode>,
     ) -> Result<This> {
         let a = self.v;
         let b = to_i64(&env, value)?;
@@ -409,103 +1034,300 @@ impl Int64 {
         if b < 0 {
             env.throw_range_error(
                 "the bit count for rotation must not be smaller than zero",
-                None,
+                Some(ErrorCode::OutOfRange.as_str()),
             )?;
 
             return Err(Error::from_reason(""));
         } else if b > u32::MAX as i64 {
             env.throw_range_error(
                 &format!("the bit count for rotation must not be bigger than {}", u32::MAX),
-                None,
+                Some(ErrorCode::OutOfRange.as_str()),
             )?;
 
             return Err(Error::from_reason(""));
         }
 
-        self.v = a.rotate_right(b as u32);
+        let b = check_shift_count(&env, b as u32, mode, "rotation")?;
+
+        self.v = a.rotate_right(b);
 
         Ok(this)
     }
 
-    /// Computes `self &= value`.
-    #[napi]
-    pub fn and(
-        &mut self,
-        this: This,
-        env: Env,
-        #[napi(ts_arg_type = "number | string | Buffer | Int64")] value: Either<&Int64, Unknown>,
-    ) -> Result<This> {
-        self.v &= to_i64(&env, value)?;
+    /// Reverses the byte order of `self`.
+    #[napi(js_name = "swapBytes")]
+    pub fn swap_bytes(&mut self, this: This) -> Result<This> {
+        self.v = self.v.swap_bytes();
 
         Ok(this)
     }
 
-    /// Computes `self |= value`.
-    #[napi]
-    pub fn or(
-        &mut self,
-        this: This,
-        env: Env,
-        #[napi(ts_arg_type = "number | string | Buffer | Int64")] value: Either<&Int64, Unknown>,
-    ) -> Result<This> {
-        self.v |= to_i64(&env, value)?;
+    /// Reverses the order of the bits of `self`. The least significant bit becomes the most significant bit.
+    #[napi(js_name = "reverseBits")]
+    pub fn reverse_bits(&mut self, this: This) -> Result<This> {
+        self.v = self.v.reverse_bits();
 
         Ok(this)
     }
 
-    /// Computes `self ^= value`.
-    #[napi]
-    pub fn xor(
-        &mut self,
-        this: This,
-        env: Env,
-        #[napi(ts_arg_type = "number | string | Buffer | Int64")] value: Either<&Int64, Unknown>,
-    ) -> Result<This> {
-        self.v ^= to_i64(&env, value)?;
+    /// Converts `self` from the native byte order to Big-Endian. On Little-Endian platforms the bytes are swapped, otherwise nothing happens.
+    #[napi(js_name = "toBigEndian")]
+    pub fn to_big_endian(&mut self, this: This) -> Result<This> {
+        self.v = self.v.to_be();
 
         Ok(this)
     }
 
-    /// Computes `self = ~(self & value)`.
-    #[napi]
-    pub fn nand(
-        &mut self,
-        this: This,
-        env: Env,
-        #[napi(ts_arg_type = "number | string | Buffer | Int64")] value: Either<&Int64, Unknown>,
-    ) -> Result<This> {
-        self.v = !(self.v & to_i64(&env, value)?);
+    /// Converts `self` from Big-Endian to the native byte order. On Little-Endian platforms the bytes are swapped, otherwise nothing happens.
+    #[napi(js_name = "fromBigEndian")]
+    pub fn from_big_endian(&mut self, this: This) -> Result<This> {
+        self.v = i64::from_be(self.v);
 
         Ok(this)
     }
 
-    /// Computes `self = ~(self | value)`.
-    #[napi]
-    pub fn nor(
-        &mut self,
-        this: This,
-        env: Env,
-        #[napi(ts_arg_type = "number | string | Buffer | Int64")] value: Either<&Int64, Unknown>,
-    ) -> Result<This> {
-        self.v = !(self.v | to_i64(&env, value)?);
+    /// Converts `self` from the native byte order to Little-Endian. On Big-Endian platforms the bytes are swapped, otherwise nothing happens.
+    #[napi(js_name = "toLittleEndian")]
+    pub fn to_little_endian(&mut self, this: This) -> Result<This> {
+        self.v = self.v.to_le();
 
         Ok(this)
     }
 
-    /// Computes `self = ~(self ^ value)`.
-    #[napi]
-    pub fn xnor(
-        &mut self,
-        this: This,
-        env: Env,
-        #[napi(ts_arg_type = "number | string | Buffer | Int64")] value: Either<&Int64, Unknown>,
-    ) -> Result<This> {
-        self.v = !(self.v ^ to_i64(&env, value)?);
+    /// Converts `self` from Little-Endian to the native byte order. On Big-Endian platforms the bytes are swapped, otherwise nothing happens.
+    #[napi(js_name = "fromLittleEndian")]
+    pub fn from_little_endian(&mut self, this: This) -> Result<This> {
+        self.v = i64::from_le(self.v);
 
         Ok(this)
     }
 
-    /// Computes `self = ~self`.
+    /// Tests whether the bit at `index` is one. The least significant bit is at index `0`.
+    ///
+    /// `index` must be between 0 and 63
+    #[napi(js_name = "getBit")]
+    pub fn get_bit(
+        &self,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] index: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<bool> {
+        let i = check_bit_index(&env, to_i64(&env, index)?)?;
+
+        Ok(self.v >> i & 1 == 1)
+    }
+
+    /// Computes `self |= 1 << index`.
+    ///
+    /// `index` must be between 0 and 63
+    #[napi(js_name = "setBit")]
+    pub fn set_bit(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] index: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        let i = check_bit_index(&env, to_i64(&env, index)?)?;
+
+        self.v |= 1 << i;
+
+        Ok(this)
+    }
+
+    /// Computes `self &= ~(1 << index)`.
+    ///
+    /// `index` must be between 0 and 63
+    #[napi(js_name = "clearBit")]
+    pub fn clear_bit(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] index: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        let i = check_bit_index(&env, to_i64(&env, index)?)?;
+
+        self.v &= !(1 << i);
+
+        Ok(this)
+    }
+
+    /// Computes `self ^= 1 << index`.
+    ///
+    /// `index` must be between 0 and 63
+    #[napi(js_name = "toggleBit")]
+    pub fn toggle_bit(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] index: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        let i = check_bit_index(&env, to_i64(&env, index)?)?;
+
+        self.v ^= 1 << i;
+
+        Ok(this)
+    }
+
+    /// Computes `self = the bit field of self which starts at offset and has width bits`.
+    ///
+    /// `offset + width` must not be bigger than 64
+    ///
+    /// @param signed Whether the most significant bit of the field is a sign bit. Default: `false`.
+    #[napi(js_name = "extractBits")]
+    pub fn extract_bits(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")]
+        offset: Either<&Int64, Unknown>,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] width: Either<
+            &Int64,
+            Unknown,
+        >,
+        signed: Option<bool>,
+    ) -> Result<This> {
+        let (offset, width) = check_bit_field(&env, to_i64(&env, offset)?, to_i64(&env, width)?)?;
+
+        self.v = extract_bits_i64(self.v, offset, width, signed.unwrap_or(false));
+
+        Ok(this)
+    }
+
+    /// Replaces the bit field of `self` which starts at `offset` and has `width` bits with the lowest `width` bits of `value`. The other bits of `value` are ignored.
+    ///
+    /// `offset + width` must not be bigger than 64
+    #[napi(js_name = "insertBits")]
+    pub fn insert_bits(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")]
+        offset: Either<&Int64, Unknown>,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] width: Either<
+            &Int64,
+            Unknown,
+        >,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        let (offset, width) = check_bit_field(&env, to_i64(&env, offset)?, to_i64(&env, width)?)?;
+        let b = to_i64(&env, value)?;
+
+        self.v = insert_bits_i64(self.v, offset, width, b);
+
+        Ok(this)
+    }
+
+    /// Computes `self &= value`.
+    #[napi]
+    pub fn and(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        self.v &= to_i64(&env, value)?;
+
+        Ok(this)
+    }
+
+    /// Computes `self |= value`.
+    #[napi]
+    pub fn or(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        self.v |= to_i64(&env, value)?;
+
+        Ok(this)
+    }
+
+    /// Computes `self ^= value`.
+    #[napi]
+    pub fn xor(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        self.v ^= to_i64(&env, value)?;
+
+        Ok(this)
+    }
+
+    /// Computes `self = ~(self & value)`.
+    #[napi]
+    pub fn nand(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        self.v = !(self.v & to_i64(&env, value)?);
+
+        Ok(this)
+    }
+
+    /// Computes `self = ~(self | value)`.
+    #[napi]
+    pub fn nor(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        self.v = !(self.v | to_i64(&env, value)?);
+
+        Ok(this)
+    }
+
+    /// Computes `self = ~(self ^ value)`.
+    #[napi]
+    pub fn xnor(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        self.v = !(self.v ^ to_i64(&env, value)?);
+
+        Ok(this)
+    }
+
+    /// Computes `self = ~self`.
     #[napi]
     pub fn not(&mut self, this: This) -> Result<This> {
         self.v = !self.v;
@@ -513,20 +1335,1067 @@ impl Int64 {
         Ok(this)
     }
 
-    /// Computes `self = -self`.
-    #[napi]
-    pub fn negative(&mut self, this: This) -> Result<This> {
-        self.v = -self.v;
+    /// Computes `self = -self`, wrapping around at the boundary of an 64-bit integer.
+    #[napi]
+    pub fn negative(&mut self, this: This) -> Result<This> {
+        self.v = self.v.wrapping_neg();
+
+        Ok(this)
+    }
+
+    /// Computes `self = |self|`, wrapping around at the boundary of an 64-bit integer.
+    #[napi]
+    pub fn abs(&mut self, this: This) -> Result<This> {
+        self.v = self.v.wrapping_abs();
+
+        Ok(this)
+    }
+
+    /// Computes `|self|` without overflow, as a 64-bit unsigned integer.
+    #[napi(js_name = "unsignedAbs")]
+    pub fn unsigned_abs(&self) -> UInt64 {
+        UInt64 {
+            v: self.v.unsigned_abs()
+        }
+    }
+
+    /// Computes `|self - value|` without overflow, as a 64-bit unsigned integer.
+    #[napi(js_name = "absDiff")]
+    pub fn abs_diff(
+        &self,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<UInt64> {
+        let a = self.v;
+        let b = to_i64(&env, value)?;
+
+        Ok(UInt64 {
+            v: a.abs_diff(b)
+        })
+    }
+
+    /// Computes `self = sign(self)`, which is `-1`, `0` or `1`.
+    #[napi]
+    pub fn signum(&mut self, this: This) -> Result<This> {
+        self.v = self.v.signum();
+
+        Ok(this)
+    }
+
+    /// Computes `self > 0`.
+    #[napi(js_name = "isPositive")]
+    pub fn is_positive(&self) -> bool {
+        self.v > 0
+    }
+
+    /// Computes `self < 0`.
+    #[napi(js_name = "isNegative")]
+    pub fn is_negative(&self) -> bool {
+        self.v < 0
+    }
+
+    /// Computes `self === 0`.
+    #[napi(js_name = "isZero")]
+    pub fn is_zero(&self) -> bool {
+        self.v == 0
+    }
+
+    /// Computes `self = gcd(self, value)`, throwing a RangeError if overflow occurred. The result is never negative.
+    #[napi]
+    pub fn gcd(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        let a = self.v;
+        let b = to_i64(&env, value)?;
+
+        match checked_gcd(a, b) {
+            Some(v) => self.v = v,
+            None => {
+                env.throw_range_error(
+                    &format!(
+                        "attempt to calculate the greatest common divisor of {a} and {b} with \
+                         overflow"
+                    ),
+                    Some(ErrorCode::Overflow.as_str()),
+                )?;
+
+                return Err(Error::from_reason(""));
+            },
+        }
+
+        Ok(this)
+    }
+
+    /// Computes `self = lcm(self, value)`, throwing a RangeError if overflow occurred. The result is never negative.
+    #[napi]
+    pub fn lcm(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        let a = self.v;
+        let b = to_i64(&env, value)?;
+
+        match checked_lcm(a, b) {
+            Some(v) => self.v = v,
+            None => {
+                env.throw_range_error(
+                    &format!(
+                        "attempt to calculate the least common multiple of {a} and {b} with \
+                         overflow"
+                    ),
+                    Some(ErrorCode::Overflow.as_str()),
+                )?;
+
+                return Err(Error::from_reason(""));
+            },
+        }
+
+        Ok(this)
+    }
+
+    /// Computes `self = floor(sqrt(self))`.
+    ///
+    /// `self` must not be smaller than zero
+    #[napi]
+    pub fn isqrt(&mut self, this: This, env: Env) -> Result<This> {
+        let a = self.v;
+
+        if a < 0 {
+            env.throw_range_error(
+                &format!(
+                    "the radicand of an integer square root must not be smaller than zero, but it \
+                     is {a}"
+                ),
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            return Err(Error::from_reason(""));
+        }
+
+        self.v = isqrt_u64(a as u64) as i64;
+
+        Ok(this)
+    }
+
+    /// Computes `floor(log2(self))`.
+    ///
+    /// `self` must be bigger than zero
+    #[napi]
+    pub fn ilog2(&self, env: Env) -> Result<u32> {
+        let a = self.v;
+
+        if a <= 0 {
+            env.throw_range_error(
+                &format!(
+                    "the argument of an integer logarithm must be bigger than zero, but it is {a}"
+                ),
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            return Err(Error::from_reason(""));
+        }
+
+        Ok(ilog_u64(a as u64, 2))
+    }
+
+    /// Computes `floor(log10(self))`.
+    ///
+    /// `self` must be bigger than zero
+    #[napi]
+    pub fn ilog10(&self, env: Env) -> Result<u32> {
+        let a = self.v;
+
+        if a <= 0 {
+            env.throw_range_error(
+                &format!(
+                    "the argument of an integer logarithm must be bigger than zero, but it is {a}"
+                ),
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            return Err(Error::from_reason(""));
+        }
+
+        Ok(ilog_u64(a as u64, 10))
+    }
+
+    /// Computes `floor(log(self) / log(base))`.
+    ///
+    /// `self` must be bigger than zero, and `base` must not be smaller than 2
+    #[napi]
+    pub fn ilog(
+        &self,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] base: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<u32> {
+        let a = self.v;
+        let b = to_i64(&env, base)?;
+
+        if a <= 0 {
+            env.throw_range_error(
+                &format!(
+                    "the argument of an integer logarithm must be bigger than zero, but it is {a}"
+                ),
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            return Err(Error::from_reason(""));
+        } else if b < 2 {
+            env.throw_range_error(
+                &format!(
+                    "the base of an integer logarithm must not be smaller than 2, but it is {b}"
+                ),
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            return Err(Error::from_reason(""));
+        }
+
+        Ok(ilog_u64(a as u64, b as u64))
+    }
+
+    /// Computes `self = (self + value) / 2` without overflow, rounding towards zero.
+    #[napi]
+    pub fn midpoint(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        let a = self.v;
+        let b = to_i64(&env, value)?;
+
+        self.v = midpoint_i64(a, b);
+
+        Ok(this)
+    }
+
+    /// Computes `self = (self + value) mod modulus` without overflow. The result is between `0` and `modulus - 1`.
+    ///
+    /// `modulus` must be bigger than zero
+    #[napi(js_name = "addMod")]
+    pub fn add_mod(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")]
+        modulus: Either<&Int64, Unknown>,
+    ) -> Result<This> {
+        let a = self.v;
+        let b = to_i64(&env, value)?;
+        let m = check_modulus(&env, modulus)?;
+
+        self.v = add_mod_i64(a, b, m);
+
+        Ok(this)
+    }
+
+    /// Computes `self = (self * value) mod modulus` without overflow. The result is between `0` and `modulus - 1`.
+    ///
+    /// `modulus` must be bigger than zero
+    #[napi(js_name = "mulMod")]
+    pub fn mul_mod(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")]
+        modulus: Either<&Int64, Unknown>,
+    ) -> Result<This> {
+        let a = self.v;
+        let b = to_i64(&env, value)?;
+        let m = check_modulus(&env, modulus)?;
+
+        self.v = mul_mod_i64(a, b, m);
+
+        Ok(this)
+    }
+
+    /// Computes `self = (self ^ exponent) mod modulus` without overflow. The result is between `0` and `modulus - 1`.
+    ///
+    /// `exponent` must not be smaller than zero, and `modulus` must be bigger than zero
+    #[napi(js_name = "powMod")]
+    pub fn pow_mod(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")]
+        exponent: Either<&Int64, Unknown>,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")]
+        modulus: Either<&Int64, Unknown>,
+    ) -> Result<This> {
+        let a = self.v;
+        let b = to_i64(&env, exponent)?;
+        let m = check_modulus(&env, modulus)?;
+
+        if b < 0 {
+            env.throw_range_error(
+                "the exponent of an integer number must not be smaller than zero",
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            return Err(Error::from_reason(""));
+        }
+
+        self.v = pow_mod_i64(a, b as u64, m);
+
+        Ok(this)
+    }
+
+    /// Computes `self = self^-1 mod modulus`, the number `x` between `0` and `modulus - 1` which makes `(self * x) mod modulus` equal `1`, throwing a RangeError if it does not exist.
+    ///
+    /// `modulus` must be bigger than zero
+    #[napi(js_name = "modInverse")]
+    pub fn mod_inverse(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")]
+        modulus: Either<&Int64, Unknown>,
+    ) -> Result<This> {
+        let a = self.v;
+        let m = check_modulus(&env, modulus)?;
+
+        match mod_inverse_i64(a, m) {
+            Some(v) => self.v = v,
+            None => {
+                env.throw_range_error(
+                    &format!("the modular inverse of {a} modulo {m} does not exist"),
+                    Some(ErrorCode::NotInvertible.as_str()),
+                )?;
+
+                return Err(Error::from_reason(""));
+            },
+        }
+
+        Ok(this)
+    }
+
+    /// Tests whether this 64-bit integer is a prime number. The test is deterministic.
+    #[napi(js_name = "isPrime")]
+    pub fn is_prime(&self) -> bool {
+        self.v > 1 && is_prime_u64(self.v as u64)
+    }
+
+    /// Computes `self = the smallest prime number bigger than self`, throwing a RangeError if overflow occurred.
+    #[napi(js_name = "nextPrime")]
+    pub fn next_prime(&mut self, this: This, env: Env) -> Result<This> {
+        let a = self.v;
+
+        match next_prime_i64(a) {
+            Some(v) => self.v = v,
+            None => {
+                env.throw_range_error(
+                    &format!("attempt to find the next prime number after {a} with overflow"),
+                    Some(ErrorCode::Overflow.as_str()),
+                )?;
+
+                return Err(Error::from_reason(""));
+            },
+        }
+
+        Ok(this)
+    }
+
+    /// Computes `self = the biggest prime number smaller than self`.
+    ///
+    /// `self` must be bigger than 2
+    #[napi(js_name = "prevPrime")]
+    pub fn prev_prime(&mut self, this: This, env: Env) -> Result<This> {
+        let a = self.v;
+
+        match prev_prime_i64(a) {
+            Some(v) => self.v = v,
+            None => {
+                env.throw_range_error(
+                    &format!("there is no prime number smaller than {a}"),
+                    Some(ErrorCode::OutOfRange.as_str()),
+                )?;
+
+                return Err(Error::from_reason(""));
+            },
+        }
+
+        Ok(this)
+    }
+
+    /// Computes the prime factors of this 64-bit integer with multiplicities, in ascending order. The factors of `1` is an empty array.
+    ///
+    /// `self` must be bigger than zero
+    #[napi]
+    pub fn factorize(&self, env: Env) -> Result<Vec<Int64>> {
+        let a = self.v;
+
+        if a <= 0 {
+            env.throw_range_error(
+                &format!("the number to factorize must be bigger than zero, but it is {a}"),
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            return Err(Error::from_reason(""));
+        }
+
+        Ok(factorize_u64(a as u64)
+            .into_iter()
+            .map(|v| Int64 {
+                v: v as i64
+            })
+            .collect())
+    }
+
+    /// Counts the number of ones in the binary representation of this 64-bit integer.
+    #[napi(js_name = "countOnes")]
+    pub fn count_ones(&self) -> u32 {
+        self.v.count_ones()
+    }
+
+    /// Counts the number of zeros in the binary representation of this 64-bit integer.
+    #[napi(js_name = "countZeros")]
+    pub fn count_zeros(&self) -> u32 {
+        self.v.count_zeros()
+    }
+
+    /// Counts the number of leading zeros in the binary representation of this 64-bit integer.
+    #[napi(js_name = "leadingZeros")]
+    pub fn leading_zeros(&self) -> u32 {
+        self.v.leading_zeros()
+    }
+
+    /// Counts the number of trailing zeros in the binary representation of this 64-bit integer.
+    #[napi(js_name = "trailingZeros")]
+    pub fn trailing_zeros(&self) -> u32 {
+        self.v.trailing_zeros()
+    }
+
+    /// Counts the number of leading ones in the binary representation of this 64-bit integer.
+    #[napi(js_name = "leadingOnes")]
+    pub fn leading_ones(&self) -> u32 {
+        self.v.leading_ones()
+    }
+
+    /// Counts the number of trailing ones in the binary representation of this 64-bit integer.
+    #[napi(js_name = "trailingOnes")]
+    pub fn trailing_ones(&self) -> u32 {
+        self.v.trailing_ones()
+    }
+
+    /// Counts the number of bits needed to represent this 64-bit integer in two's complement, excluding the sign bit. It is `0` for `0` and `-1`.
+    #[napi(js_name = "bitLength")]
+    pub fn bit_length(&self) -> u32 {
+        bit_length_i64(self.v)
+    }
+
+    /// Tests whether this 64-bit integer is a power of two.
+    #[napi(js_name = "isPowerOfTwo")]
+    pub fn is_power_of_two(&self) -> bool {
+        self.v > 0 && self.v & (self.v - 1) == 0
+    }
+
+    /// Computes `self = the smallest power of two which is not smaller than self`, throwing a RangeError if overflow occurred. It is `1` if `self` is not bigger than `1`.
+    #[napi(js_name = "nextPowerOfTwo")]
+    pub fn next_power_of_two(&mut self, this: This, env: Env) -> Result<This> {
+        let a = self.v;
+
+        match checked_next_power_of_two(a) {
+            Some(v) => self.v = v,
+            None => {
+                env.throw_range_error(
+                    &format!("attempt to calculate the next power of two of {a} with overflow"),
+                    Some(ErrorCode::Overflow.as_str()),
+                )?;
+
+                return Err(Error::from_reason(""));
+            },
+        }
+
+        Ok(this)
+    }
+
+    /// Computes `self += value`, throwing a RangeError if overflow occurred.
+    #[napi(js_name = "checkedAdd")]
+    pub fn checked_add(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        let a = self.v;
+        let b = to_i64(&env, value)?;
+
+        match a.checked_add(b) {
+            Some(v) => self.v = v,
+            None => {
+                env.throw_range_error(
+                    &format!("attempt to add {a} and {b} with overflow"),
+                    Some(ErrorCode::Overflow.as_str()),
+                )?;
+
+                return Err(Error::from_reason(""));
+            },
+        }
+
+        Ok(this)
+    }
+
+    /// Computes `self -= value`, throwing a RangeError if overflow occurred.
+    #[napi(js_name = "checkedSubtract")]
+    pub fn checked_subtract(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        let a = self.v;
+        let b = to_i64(&env, value)?;
+
+        match a.checked_sub(b) {
+            Some(v) => self.v = v,
+            None => {
+                env.throw_range_error(
+                    &format!("attempt to subtract {b} from {a} with overflow"),
+                    Some(ErrorCode::Overflow.as_str()),
+                )?;
+
+                return Err(Error::from_reason(""));
+            },
+        }
+
+        Ok(this)
+    }
+
+    /// Computes `self *= value`, throwing a RangeError if overflow occurred.
+    #[napi(js_name = "checkedMultiply")]
+    pub fn checked_multiply(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        let a = self.v;
+        let b = to_i64(&env, value)?;
+
+        match a.checked_mul(b) {
+            Some(v) => self.v = v,
+            None => {
+                env.throw_range_error(
+                    &format!("attempt to multiply {a} by {b} with overflow"),
+                    Some(ErrorCode::Overflow.as_str()),
+                )?;
+
+                return Err(Error::from_reason(""));
+            },
+        }
+
+        Ok(this)
+    }
+
+    /// Computes `self /= value`, throwing a RangeError if overflow occurred.
+    #[napi(js_name = "checkedDivide")]
+    pub fn checked_divide(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        let a = self.v;
+        let input = value.raw_input();
+        let b = to_i64(&env, value)?;
+
+        if b == 0 {
+            return throw_divide_by_zero(
+                &env,
+                format!("attempt to divide {a} by zero"),
+                input,
+                Int64 {
+                    v: b
+                },
+            );
+        }
+
+        match a.checked_div(b) {
+            Some(v) => self.v = v,
+            None => {
+                env.throw_range_error(
+                    &format!("attempt to divide {a} by {b} with overflow"),
+                    Some(ErrorCode::Overflow.as_str()),
+                )?;
+
+                return Err(Error::from_reason(""));
+            },
+        }
+
+        Ok(this)
+    }
+
+    /// Computes `self **= value`, throwing a RangeError if overflow occurred.
+    ///
+    /// `value` must not be smaller than zero
+    #[napi(js_name = "checkedPow")]
+    pub fn checked_pow(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        let a = self.v;
+        let b = to_i64(&env, value)?;
+
+        if b < 0 {
+            env.throw_range_error(
+                "the exponent of an integer number must not be smaller than zero",
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            return Err(Error::from_reason(""));
+        } else if b > u32::MAX as i64 {
+            env.throw_range_error(
+                &format!("the exponent of an integer number must not be bigger than {}", u32::MAX),
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            return Err(Error::from_reason(""));
+        }
+
+        match a.checked_pow(b as u32) {
+            Some(v) => self.v = v,
+            None => {
+                env.throw_range_error(
+                    &format!("attempt to raise {a} to the power of {b} with overflow"),
+                    Some(ErrorCode::Overflow.as_str()),
+                )?;
+
+                return Err(Error::from_reason(""));
+            },
+        }
+
+        Ok(this)
+    }
+
+    /// Computes `self <<= value`, throwing a RangeError if overflow occurred.
+    ///
+    /// Unlike `shiftLeft`, the bit count is not masked, and the shift fails if any set bit (or the sign) would be shifted out.
+    #[napi(js_name = "checkedShl")]
+    pub fn checked_shift_left(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        let a = self.v;
+        let b = to_i64(&env, value)?;
+
+        if b < 0 {
+            env.throw_range_error(
+                "the bit count for shift must not be smaller than zero",
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            return Err(Error::from_reason(""));
+        } else if b > u32::MAX as i64 {
+            env.throw_range_error(
+                &format!("the bit count for shift must not be bigger than {}", u32::MAX),
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            return Err(Error::from_reason(""));
+        }
+
+        match checked_shl(a, b as u32) {
+            Some(v) => self.v = v,
+            None => {
+                env.throw_range_error(
+                    &format!("attempt to shift {a} left by {b} with overflow"),
+                    Some(ErrorCode::Overflow.as_str()),
+                )?;
+
+                return Err(Error::from_reason(""));
+            },
+        }
+
+        Ok(this)
+    }
+
+    /// Computes `self = -self`, throwing a RangeError if overflow occurred.
+    #[napi(js_name = "checkedNeg")]
+    pub fn checked_negative(&mut self, this: This, env: Env) -> Result<This> {
+        let a = self.v;
+
+        match a.checked_neg() {
+            Some(v) => self.v = v,
+            None => {
+                env.throw_range_error(
+                    &format!("attempt to negate {a} with overflow"),
+                    Some(ErrorCode::Overflow.as_str()),
+                )?;
+
+                return Err(Error::from_reason(""));
+            },
+        }
+
+        Ok(this)
+    }
+
+    /// Computes `self += value`, saturating at the boundary of an 64-bit integer.
+    #[napi(js_name = "saturatingAdd")]
+    pub fn saturating_add(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        let a = self.v;
+        let b = to_i64(&env, value)?;
+
+        self.v = a.saturating_add(b);
+
+        Ok(this)
+    }
+
+    /// Computes `self -= value`, saturating at the boundary of an 64-bit integer.
+    #[napi(js_name = "saturatingSubtract")]
+    pub fn saturating_subtract(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        let a = self.v;
+        let b = to_i64(&env, value)?;
+
+        self.v = a.saturating_sub(b);
+
+        Ok(this)
+    }
+
+    /// Computes `self *= value`, saturating at the boundary of an 64-bit integer.
+    #[napi(js_name = "saturatingMultiply")]
+    pub fn saturating_multiply(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        let a = self.v;
+        let b = to_i64(&env, value)?;
+
+        self.v = a.saturating_mul(b);
+
+        Ok(this)
+    }
+
+    /// Computes `self **= value`, saturating at the boundary of an 64-bit integer.
+    ///
+    /// `value` must not be smaller than zero
+    #[napi(js_name = "saturatingPow")]
+    pub fn saturating_pow(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<This> {
+        let a = self.v;
+        let b = to_i64(&env, value)?;
+
+        if b < 0 {
+            env.throw_range_error(
+                "the exponent of an integer number must not be smaller than zero",
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            return Err(Error::from_reason(""));
+        } else if b > u32::MAX as i64 {
+            env.throw_range_error(
+                &format!("the exponent of an integer number must not be bigger than {}", u32::MAX),
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            return Err(Error::from_reason(""));
+        }
+
+        self.v = a.saturating_pow(b as u32);
 
         Ok(this)
     }
 
+    /// Computes `self = -self`, saturating at the boundary of an 64-bit integer.
+    #[napi(js_name = "saturatingNeg")]
+    pub fn saturating_negative(&mut self, this: This) -> Result<This> {
+        self.v = self.v.saturating_neg();
+
+        Ok(this)
+    }
+
+    /// Computes `self += value`, wrapping around at the boundary of an 64-bit integer, and returns the result along with whether an overflow occurred.
+    #[napi(js_name = "overflowingAdd")]
+    pub fn overflowing_add(
+        &mut self,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<OverflowingResult> {
+        let a = self.v;
+        let b = to_i64(&env, value)?;
+
+        let (v, overflowed) = a.overflowing_add(b);
+
+        self.v = v;
+
+        Ok(OverflowingResult {
+            value: self.clone(),
+            overflowed,
+        })
+    }
+
+    /// Computes `self -= value`, wrapping around at the boundary of an 64-bit integer, and returns the result along with whether an overflow occurred.
+    #[napi(js_name = "overflowingSubtract")]
+    pub fn overflowing_subtract(
+        &mut self,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<OverflowingResult> {
+        let a = self.v;
+        let b = to_i64(&env, value)?;
+
+        let (v, overflowed) = a.overflowing_sub(b);
+
+        self.v = v;
+
+        Ok(OverflowingResult {
+            value: self.clone(),
+            overflowed,
+        })
+    }
+
+    /// Computes `self *= value`, wrapping around at the boundary of an 64-bit integer, and returns the result along with whether an overflow occurred.
+    #[napi(js_name = "overflowingMultiply")]
+    pub fn overflowing_multiply(
+        &mut self,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<OverflowingResult> {
+        let a = self.v;
+        let b = to_i64(&env, value)?;
+
+        let (v, overflowed) = a.overflowing_mul(b);
+
+        self.v = v;
+
+        Ok(OverflowingResult {
+            value: self.clone(),
+            overflowed,
+        })
+    }
+
+    /// Computes `self **= value`, wrapping around at the boundary of an 64-bit integer, and returns the result along with whether an overflow occurred.
+    ///
+    /// `value` must not be smaller than zero
+    #[napi(js_name = "overflowingPow")]
+    pub fn overflowing_pow(
+        &mut self,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<OverflowingResult> {
+        let a = self.v;
+        let b = to_i64(&env, value)?;
+
+        if b < 0 {
+            env.throw_range_error(
+                "the exponent of an integer number must not be smaller than zero",
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            return Err(Error::from_reason(""));
+        } else if b > u32::MAX as i64 {
+            env.throw_range_error(
+                &format!("the exponent of an integer number must not be bigger than {}", u32::MAX),
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            return Err(Error::from_reason(""));
+        }
+
+        let (v, overflowed) = a.overflowing_pow(b as u32);
+
+        self.v = v;
+
+        Ok(OverflowingResult {
+            value: self.clone(),
+            overflowed,
+        })
+    }
+
+    /// Computes `self <<= value`, wrapping around at the boundary of an 64-bit integer, and returns the result along with whether an overflow occurred.
+    ///
+    /// `value` must not be smaller than zero. The overflow flag is set if `value` is not smaller than 64, in which case the bit count is masked to 6 bits.
+    #[napi(js_name = "overflowingShl")]
+    pub fn overflowing_shift_left(
+        &mut self,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<OverflowingResult> {
+        let a = self.v;
+        let b = to_i64(&env, value)?;
+
+        if b < 0 {
+            env.throw_range_error(
+                "the bit count for shift must not be smaller than zero",
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            return Err(Error::from_reason(""));
+        } else if b > u32::MAX as i64 {
+            env.throw_range_error(
+                &format!("the bit count for shift must not be bigger than {}", u32::MAX),
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            return Err(Error::from_reason(""));
+        }
+
+        let (v, overflowed) = a.overflowing_shl(b as u32);
+
+        self.v = v;
+
+        Ok(OverflowingResult {
+            value: self.clone(),
+            overflowed,
+        })
+    }
+
+    /// Computes `self >>= value`, wrapping around at the boundary of an 64-bit integer, and returns the result along with whether an overflow occurred.
+    ///
+    /// `value` must not be smaller than zero. The overflow flag is set if `value` is not smaller than 64, in which case the bit count is masked to 6 bits.
+    #[napi(js_name = "overflowingShr")]
+    pub fn overflowing_shift_right(
+        &mut self,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<OverflowingResult> {
+        let a = self.v;
+        let b = to_i64(&env, value)?;
+
+        if b < 0 {
+            env.throw_range_error(
+                "the bit count for shift must not be smaller than zero",
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            return Err(Error::from_reason(""));
+        } else if b > u32::MAX as i64 {
+            env.throw_range_error(
+                &format!("the bit count for shift must not be bigger than {}", u32::MAX),
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            return Err(Error::from_reason(""));
+        }
+
+        let (v, overflowed) = a.overflowing_shr(b as u32);
+
+        self.v = v;
+
+        Ok(OverflowingResult {
+            value: self.clone(),
+            overflowed,
+        })
+    }
+
+    /// Computes `self = -self`, wrapping around at the boundary of an 64-bit integer, and returns the result along with whether an overflow occurred.
+    #[napi(js_name = "overflowingNeg")]
+    pub fn overflowing_negative(&mut self) -> OverflowingResult {
+        let (v, overflowed) = self.v.overflowing_neg();
+
+        self.v = v;
+
+        OverflowingResult {
+            value: self.clone(),
+            overflowed,
+        }
+    }
+
     /// Computes `self === value`.
     #[napi]
     pub fn eq(
         &self,
         env: Env,
-        #[napi(ts_arg_type = "number | string | Buffer | Int64")] value: Either<&Int64, Unknown>,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
     ) -> Result<bool> {
         let a = self.v;
         let b = to_i64(&env, value)?;
@@ -539,7 +2408,10 @@ impl Int64 {
     pub fn ne(
         &self,
         env: Env,
-        #[napi(ts_arg_type = "number | string | Buffer | Int64")] value: Either<&Int64, Unknown>,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
     ) -> Result<bool> {
         let a = self.v;
         let b = to_i64(&env, value)?;
@@ -552,7 +2424,10 @@ impl Int64 {
     pub fn gt(
         &self,
         env: Env,
-        #[napi(ts_arg_type = "number | string | Buffer | Int64")] value: Either<&Int64, Unknown>,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
     ) -> Result<bool> {
         let a = self.v;
         let b = to_i64(&env, value)?;
@@ -565,7 +2440,10 @@ impl Int64 {
     pub fn gte(
         &self,
         env: Env,
-        #[napi(ts_arg_type = "number | string | Buffer | Int64")] value: Either<&Int64, Unknown>,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
     ) -> Result<bool> {
         let a = self.v;
         let b = to_i64(&env, value)?;
@@ -578,7 +2456,10 @@ impl Int64 {
     pub fn lt(
         &self,
         env: Env,
-        #[napi(ts_arg_type = "number | string | Buffer | Int64")] value: Either<&Int64, Unknown>,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
     ) -> Result<bool> {
         let a = self.v;
         let b = to_i64(&env, value)?;
@@ -591,7 +2472,10 @@ impl Int64 {
     pub fn lte(
         &self,
         env: Env,
-        #[napi(ts_arg_type = "number | string | Buffer | Int64")] value: Either<&Int64, Unknown>,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
     ) -> Result<bool> {
         let a = self.v;
         let b = to_i64(&env, value)?;
@@ -606,7 +2490,10 @@ impl Int64 {
     pub fn comp(
         &self,
         env: Env,
-        #[napi(ts_arg_type = "number | string | Buffer | Int64")] value: Either<&Int64, Unknown>,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
     ) -> Result<Ordering> {
         let a = self.v;
         let b = to_i64(&env, value)?;
@@ -620,7 +2507,10 @@ impl Int64 {
         &mut self,
         this: This,
         env: Env,
-        #[napi(ts_arg_type = "number | string | Buffer | Int64")] value: Either<&Int64, Unknown>,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] value: Either<
+            &Int64,
+            Unknown,
+        >,
     ) -> Result<This> {
         let a = self.v;
         let b = to_i64(&env, value)?;
@@ -630,6 +2520,35 @@ impl Int64 {
         Ok(this)
     }
 
+    /// Computes `a * b` without overflow, and returns the exact product as a 128-bit integer.
+    #[napi(js_name = "widenMultiply")]
+    pub fn widen_multiply(
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+            &Int64,
+            Unknown,
+        >,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+            &Int64,
+            Unknown,
+        >,
+    ) -> Result<Int128> {
+        let a = to_i64(&env, a)?;
+        let b = to_i64(&env, b)?;
+
+        Ok(Int128 {
+            v: a as i128 * b as i128
+        })
+    }
+
+    /// Reinterprets the bits of this 64-bit signed integer as a 64-bit unsigned integer.
+    #[napi(js_name = "toUnsigned")]
+    pub fn to_unsigned(&self) -> UInt64 {
+        UInt64 {
+            v: self.v as u64
+        }
+    }
+
     #[allow(clippy::should_implement_trait)]
     /// Clones this `Int64` object.
     #[napi]
@@ -1,6 +1,6 @@
 import { inspect } from "node:util";
 
-import { Int64 } from "../index.cjs";
+import { Decimal64, Fixed64, Int128, Int64, UInt128, UInt64 } from "../index.cjs";
 
 export * from "../index.cjs";
 
@@ -9,3 +9,33 @@ Object.assign(Int64.prototype, {
         return (this as unknown as Int64).toDecimal();
     },
 });
+
+Object.assign(UInt64.prototype, {
+    [inspect.custom](): string {
+        return (this as unknown as UInt64).toDecimal();
+    },
+});
+
+Object.assign(Int128.prototype, {
+    [inspect.custom](): string {
+        return (this as unknown as Int128).toDecimal();
+    },
+});
+
+Object.assign(UInt128.prototype, {
+    [inspect.custom](): string {
+        return (this as unknown as UInt128).toDecimal();
+    },
+});
+
+Object.assign(Decimal64.prototype, {
+    [inspect.custom](): string {
+        return (this as unknown as Decimal64).toString();
+    },
+});
+
+Object.assign(Fixed64.prototype, {
+    [inspect.custom](): string {
+        return (this as unknown as Fixed64).toString();
+    },
+});
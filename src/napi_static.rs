@@ -1,14 +1,59 @@
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
 
-use crate::{to_i64, Int64, Ordering};
+use crate::{
+    add_mod_i64, bit_length_i64, check_modulus, check_shift_count, checked_gcd, checked_lcm,
+    checked_next_power_of_two, checked_shl, div_rem, factorize_u64, ilog_u64, is_prime_u64,
+    isqrt_u64, midpoint_i64, mod_inverse_i64, mul_mod_i64, next_prime_i64, pow_mod_i64,
+    prev_prime_i64, shl_i64, shr_i64, shr_unsigned_i64, throw_divide_by_zero, to_i64, DivModResult,
+    DivisionRounding, ErrorCode, Int64, Ordering, OverflowingResult, ParseOptions, RawInput,
+    ShiftMode, UInt64,
+};
+
+/// Parses a string as a 64-bit integer.
+///
+/// @param options A radix between 2 and 36, or the parsing options. If the radix is not set, it is decided by the `0b`, `0o` or `0x` prefix of the string, or is 10 without any prefix.
+#[napi]
+pub fn parse(
+    env: Env,
+    value: String,
+    #[napi(ts_arg_type = "number | ParseOptions")] options: Option<Either<u32, ParseOptions>>,
+) -> Result<Int64> {
+    Int64::parse(env, value, options)
+}
+
+/// Formats a 64-bit integer as a string, like `Number.prototype.toString`. Negative numbers are formatted with a minus sign rather than in two's complement.
+///
+/// @param radix An integer between 2 and 36. Default: `10`.
+#[napi]
+pub fn format(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    radix: Option<u32>,
+) -> Result<String> {
+    let a = to_i64(&env, a)?;
+
+    Int64 {
+        v: a
+    }
+    .to_string(env, radix)
+}
 
 /// Computes `a + b`, wrapping around at the boundary of an 64-bit integer.
 #[napi]
 pub fn add(
     env: Env,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] a: Either<&Int64, Unknown>,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
 ) -> Result<Int64> {
     let a = to_i64(&env, a)?;
     let b = to_i64(&env, b)?;
@@ -22,8 +67,14 @@ pub fn add(
 #[napi]
 pub fn subtract(
     env: Env,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] a: Either<&Int64, Unknown>,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
 ) -> Result<Int64> {
     let a = to_i64(&env, a)?;
     let b = to_i64(&env, b)?;
@@ -37,8 +88,14 @@ pub fn subtract(
 #[napi]
 pub fn multiply(
     env: Env,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] a: Either<&Int64, Unknown>,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
 ) -> Result<Int64> {
     let a = to_i64(&env, a)?;
     let b = to_i64(&env, b)?;
@@ -52,40 +109,290 @@ pub fn multiply(
 #[napi]
 pub fn divide(
     env: Env,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] a: Either<&Int64, Unknown>,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
 ) -> Result<Int64> {
     let a = to_i64(&env, a)?;
+    let input = b.raw_input();
     let b = to_i64(&env, b)?;
 
+    if b == 0 {
+        return throw_divide_by_zero(
+            &env,
+            format!("attempt to divide {a} by zero"),
+            input,
+            Int64 {
+                v: b
+            },
+        );
+    }
+
     Ok(Int64 {
         v: a.wrapping_div(b)
     })
 }
 
-/// Computes `a % b`.
+/// Computes `a % b`, wrapping around at the boundary of an 64-bit integer.
 #[napi(js_name = "mod")]
 pub fn modulo(
     env: Env,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] a: Either<&Int64, Unknown>,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
 ) -> Result<Int64> {
     let a = to_i64(&env, a)?;
+    let input = b.raw_input();
     let b = to_i64(&env, b)?;
 
+    if b == 0 {
+        return throw_divide_by_zero(
+            &env,
+            format!("attempt to calculate the remainder of {a} with a divisor of zero"),
+            input,
+            Int64 {
+                v: b
+            },
+        );
+    }
+
     Ok(Int64 {
         v: a.wrapping_rem(b)
     })
 }
 
+/// Computes the Euclidean quotient of `a / b`, wrapping around at the boundary of an 64-bit integer. The remainder of the Euclidean division is never negative.
+#[napi(js_name = "divEuclid")]
+pub fn div_euclid(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+    let input = b.raw_input();
+    let b = to_i64(&env, b)?;
+
+    if b == 0 {
+        return throw_divide_by_zero(
+            &env,
+            format!("attempt to divide {a} by zero"),
+            input,
+            Int64 {
+                v: b
+            },
+        );
+    }
+
+    Ok(Int64 {
+        v: div_rem(a, b, DivisionRounding::Euclid).0
+    })
+}
+
+/// Computes the least non-negative remainder of `a / b`, wrapping around at the boundary of an 64-bit integer.
+#[napi(js_name = "remEuclid")]
+pub fn rem_euclid(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+    let input = b.raw_input();
+    let b = to_i64(&env, b)?;
+
+    if b == 0 {
+        return throw_divide_by_zero(
+            &env,
+            format!("attempt to calculate the remainder of {a} with a divisor of zero"),
+            input,
+            Int64 {
+                v: b
+            },
+        );
+    }
+
+    Ok(Int64 {
+        v: div_rem(a, b, DivisionRounding::Euclid).1
+    })
+}
+
+/// Computes the quotient of `a / b` rounded towards negative infinity, wrapping around at the boundary of an 64-bit integer.
+#[napi(js_name = "divFloor")]
+pub fn div_floor(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+    let input = b.raw_input();
+    let b = to_i64(&env, b)?;
+
+    if b == 0 {
+        return throw_divide_by_zero(
+            &env,
+            format!("attempt to divide {a} by zero"),
+            input,
+            Int64 {
+                v: b
+            },
+        );
+    }
+
+    Ok(Int64 {
+        v: div_rem(a, b, DivisionRounding::Floor).0
+    })
+}
+
+/// Computes the remainder of `a / b` with the quotient rounded towards negative infinity, wrapping around at the boundary of an 64-bit integer. The remainder has the same sign as `b`.
+#[napi(js_name = "modFloor")]
+pub fn mod_floor(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+    let input = b.raw_input();
+    let b = to_i64(&env, b)?;
+
+    if b == 0 {
+        return throw_divide_by_zero(
+            &env,
+            format!("attempt to calculate the remainder of {a} with a divisor of zero"),
+            input,
+            Int64 {
+                v: b
+            },
+        );
+    }
+
+    Ok(Int64 {
+        v: div_rem(a, b, DivisionRounding::Floor).1
+    })
+}
+
+/// Computes the quotient of `a / b` rounded towards positive infinity, wrapping around at the boundary of an 64-bit integer.
+#[napi(js_name = "divCeil")]
+pub fn div_ceil(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+    let input = b.raw_input();
+    let b = to_i64(&env, b)?;
+
+    if b == 0 {
+        return throw_divide_by_zero(
+            &env,
+            format!("attempt to divide {a} by zero"),
+            input,
+            Int64 {
+                v: b
+            },
+        );
+    }
+
+    Ok(Int64 {
+        v: div_rem(a, b, DivisionRounding::Ceil).0
+    })
+}
+
+/// Computes the quotient and the remainder of `a / b` together, wrapping around at the boundary of an 64-bit integer.
+///
+/// @param rounding How the quotient is rounded. Default: `"trunc"`.
+#[napi(js_name = "divMod")]
+pub fn div_mod(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+    rounding: Option<DivisionRounding>,
+) -> Result<DivModResult> {
+    let a = to_i64(&env, a)?;
+    let input = b.raw_input();
+    let b = to_i64(&env, b)?;
+
+    if b == 0 {
+        return throw_divide_by_zero(
+            &env,
+            format!("attempt to divide {a} by zero"),
+            input,
+            Int64 {
+                v: b
+            },
+        );
+    }
+
+    let (quotient, remainder) = div_rem(a, b, rounding.unwrap_or(DivisionRounding::Trunc));
+
+    Ok(DivModResult {
+        quotient:  Int64 {
+            v: quotient
+        },
+        remainder: Int64 {
+            v: remainder
+        },
+    })
+}
+
 /// Computes `a ^ b`, wrapping around at the boundary of an 64-bit integer.
 ///
 /// `b` must not be smaller than zero
 #[napi]
 pub fn pow(
     env: Env,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] a: Either<&Int64, Unknown>,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
 ) -> Result<Int64> {
     let a = to_i64(&env, a)?;
     let b = to_i64(&env, b)?;
@@ -93,14 +400,14 @@ pub fn pow(
     if b < 0 {
         env.throw_range_error(
             "the exponent of an integer number must not be smaller than zero",
-            None,
+            Some(ErrorCode::OutOfRange.as_str()),
         )?;
 
         return Err(Error::from_reason(""));
     } else if b > u32::MAX as i64 {
         env.throw_range_error(
             &format!("the exponent of an integer number must not be bigger than {}", u32::MAX),
-            None,
+            Some(ErrorCode::OutOfRange.as_str()),
         )?;
 
         return Err(Error::from_reason(""));
@@ -114,150 +421,316 @@ pub fn pow(
 /// Computes `a << b`, wrapping around at the boundary of an 64-bit integer.
 ///
 /// `b` must not be smaller than zero
+///
+/// @param mode How a bit count which is not smaller than 64 is treated. Default: `"mask"`.
 #[napi(js_name = "shiftLeft")]
 pub fn shift_left(
     env: Env,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] a: Either<&Int64, Unknown>,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+    mode: Option<ShiftMode>,
 ) -> Result<Int64> {
     let a = to_i64(&env, a)?;
     let b = to_i64(&env, b)?;
 
     if b < 0 {
-        env.throw_range_error("the bit count for shift must not be smaller than zero", None)?;
+        env.throw_range_error(
+            "the bit count for shift must not be smaller than zero",
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
 
         return Err(Error::from_reason(""));
     } else if b > u32::MAX as i64 {
         env.throw_range_error(
             &format!("the bit count for shift must not be bigger than {}", u32::MAX),
-            None,
+            Some(ErrorCode::OutOfRange.as_str()),
         )?;
 
         return Err(Error::from_reason(""));
     }
 
+    let b = check_shift_count(&env, b as u32, mode, "shift")?;
+
     Ok(Int64 {
-        v: a.wrapping_shl(b as u32)
+        v: shl_i64(a, b, mode)
     })
 }
 
 /// Computes `a >> b`, wrapping around at the boundary of an 64-bit integer.
 ///
 /// `b` must not be smaller than zero
+///
+/// @param mode How a bit count which is not smaller than 64 is treated. Default: `"mask"`.
 #[napi(js_name = "shiftRight")]
 pub fn shift_right(
     env: Env,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] a: Either<&Int64, Unknown>,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+    mode: Option<ShiftMode>,
 ) -> Result<Int64> {
     let a = to_i64(&env, a)?;
     let b = to_i64(&env, b)?;
 
     if b < 0 {
-        env.throw_range_error("the bit count for shift must not be smaller than zero", None)?;
+        env.throw_range_error(
+            "the bit count for shift must not be smaller than zero",
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
 
         return Err(Error::from_reason(""));
     } else if b > u32::MAX as i64 {
         env.throw_range_error(
             &format!("the bit count for shift must not be bigger than {}", u32::MAX),
-            None,
+            Some(ErrorCode::OutOfRange.as_str()),
         )?;
 
         return Err(Error::from_reason(""));
     }
 
+    let b = check_shift_count(&env, b as u32, mode, "shift")?;
+
     Ok(Int64 {
-        v: a.wrapping_shr(b as u32)
+        v: shr_i64(a, b, mode)
     })
 }
 
 /// Computes `a >>> b`, wrapping around at the boundary of an 64-bit integer.
 ///
 /// `b` must not be smaller than zero
+///
+/// @param mode How a bit count which is not smaller than 64 is treated. Default: `"mask"`.
 #[napi(js_name = "shiftRightUnsigned")]
 pub fn shift_right_unsigned(
     env: Env,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] a: Either<&Int64, Unknown>,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+    mode: Option<ShiftMode>,
 ) -> Result<Int64> {
     let a = to_i64(&env, a)?;
     let b = to_i64(&env, b)?;
 
     if b < 0 {
-        env.throw_range_error("the bit count for shift must not be smaller than zero", None)?;
+        env.throw_range_error(
+            "the bit count for shift must not be smaller than zero",
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
 
         return Err(Error::from_reason(""));
     } else if b > u32::MAX as i64 {
         env.throw_range_error(
             &format!("the bit count for shift must not be bigger than {}", u32::MAX),
-            None,
+            Some(ErrorCode::OutOfRange.as_str()),
         )?;
 
         return Err(Error::from_reason(""));
     }
 
+    let b = check_shift_count(&env, b as u32, mode, "shift")?;
+
     Ok(Int64 {
-        v: (a as u64).wrapping_shr(b as u32) as i64
+        v: shr_unsigned_i64(a, b, mode)
     })
 }
 
 /// Shifts the bits to the left by a specified amount n, wrapping the truncated bits to the beginning of the resulting 64-bit integer.
 ///
 /// `b` must not be smaller than zero
+///
+/// @param mode How a bit count which is not smaller than 64 is treated. Default: `"mask"`.
 #[napi(js_name = "rotateLeft")]
 pub fn rotate_left(
     env: Env,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] a: Either<&Int64, Unknown>,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+    mode: Option<ShiftMode>,
 ) -> Result<Int64> {
     let a = to_i64(&env, a)?;
     let b = to_i64(&env, b)?;
 
     if b < 0 {
-        env.throw_range_error("the bit count for rotation must not be smaller than zero", None)?;
+        env.throw_range_error(
+            "the bit count for rotation must not be smaller than zero",
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
 
         return Err(Error::from_reason(""));
     } else if b > u32::MAX as i64 {
         env.throw_range_error(
             &format!("the bit count for rotation must not be bigger than {}", u32::MAX),
-            None,
+            Some(ErrorCode::OutOfRange.as_str()),
         )?;
 
         return Err(Error::from_reason(""));
     }
 
+    let b = check_shift_count(&env, b as u32, mode, "rotation")?;
+
     Ok(Int64 {
-        v: a.rotate_left(b as u32)
+        v: a.rotate_left(b)
     })
 }
 
 /// Shifts the bits to the right by a specified amount n, wrapping the truncated bits to the beginning of the resulting 64-bit integer.
 ///
 /// `b` must not be smaller than zero
+///
+/// @param mode How a bit count which is not smaller than 64 is treated. Default: `"mask"`.
 #[napi(js_name = "rotateRight")]
 pub fn rotate_right(
     env: Env,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] a: Either<&Int64, Unknown>,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+    mode: Option<ShiftMode>,
 ) -> Result<Int64> {
     let a = to_i64(&env, a)?;
     let b = to_i64(&env, b)?;
 
     if b < 0 {
-        env.throw_range_error("the bit count for rotation must not be smaller than zero", None)?;
+        env.throw_range_error(
+            "the bit count for rotation must not be smaller than zero",
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
 
         return Err(Error::from_reason(""));
     } else if b > u32::MAX as i64 {
         env.throw_range_error(
             &format!("the bit count for rotation must not be bigger than {}", u32::MAX),
-            None,
+            Some(ErrorCode::OutOfRange.as_str()),
         )?;
 
         return Err(Error::from_reason(""));
     }
 
+    let b = check_shift_count(&env, b as u32, mode, "rotation")?;
+
+    Ok(Int64 {
+        v: a.rotate_right(b)
+    })
+}
+
+/// Reverses the byte order of `a`.
+#[napi(js_name = "swapBytes")]
+pub fn swap_bytes(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+
+    Ok(Int64 {
+        v: a.swap_bytes()
+    })
+}
+
+/// Reverses the order of the bits of `a`. The least significant bit becomes the most significant bit.
+#[napi(js_name = "reverseBits")]
+pub fn reverse_bits(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+
+    Ok(Int64 {
+        v: a.reverse_bits()
+    })
+}
+
+/// Converts `a` from the native byte order to Big-Endian. On Little-Endian platforms the bytes are swapped, otherwise nothing happens.
+#[napi(js_name = "toBigEndian")]
+pub fn to_big_endian(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+
+    Ok(Int64 {
+        v: a.to_be()
+    })
+}
+
+/// Converts `a` from Big-Endian to the native byte order. On Little-Endian platforms the bytes are swapped, otherwise nothing happens.
+#[napi(js_name = "fromBigEndian")]
+pub fn from_big_endian(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+
+    Ok(Int64 {
+        v: i64::from_be(a)
+    })
+}
+
+/// Converts `a` from the native byte order to Little-Endian. On Big-Endian platforms the bytes are swapped, otherwise nothing happens.
+#[napi(js_name = "toLittleEndian")]
+pub fn to_little_endian(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+
+    Ok(Int64 {
+        v: a.to_le()
+    })
+}
+
+/// Converts `a` from Little-Endian to the native byte order. On Big-Endian platforms the bytes are swapped, otherwise nothing happens.
+#[napi(js_name = "fromLittleEndian")]
+pub fn from_little_endian(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+
     Ok(Int64 {
-        v: a.rotate_right(b as u32)
+        v: i64::from_le(a)
     })
 }
 
@@ -265,8 +738,14 @@ pub fn rotate_right(
 #[napi]
 pub fn and(
     env: Env,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] a: Either<&Int64, Unknown>,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
 ) -> Result<Int64> {
     let a = to_i64(&env, a)?;
     let b = to_i64(&env, b)?;
@@ -280,8 +759,14 @@ pub fn and(
 #[napi]
 pub fn or(
     env: Env,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] a: Either<&Int64, Unknown>,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
 ) -> Result<Int64> {
     let a = to_i64(&env, a)?;
     let b = to_i64(&env, b)?;
@@ -295,8 +780,14 @@ pub fn or(
 #[napi]
 pub fn xor(
     env: Env,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] a: Either<&Int64, Unknown>,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
 ) -> Result<Int64> {
     let a = to_i64(&env, a)?;
     let b = to_i64(&env, b)?;
@@ -310,8 +801,14 @@ pub fn xor(
 #[napi]
 pub fn nand(
     env: Env,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] a: Either<&Int64, Unknown>,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
 ) -> Result<Int64> {
     let mut n = and(env, a, b)?;
 
@@ -324,8 +821,14 @@ pub fn nand(
 #[napi]
 pub fn nor(
     env: Env,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] a: Either<&Int64, Unknown>,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
 ) -> Result<Int64> {
     let mut n = or(env, a, b)?;
 
@@ -338,8 +841,14 @@ pub fn nor(
 #[napi]
 pub fn xnor(
     env: Env,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] a: Either<&Int64, Unknown>,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
 ) -> Result<Int64> {
     let mut n = xor(env, a, b)?;
 
@@ -352,7 +861,10 @@ pub fn xnor(
 #[napi]
 pub fn not(
     env: Env,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] a: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
 ) -> Result<Int64> {
     let a = to_i64(&env, a)?;
 
@@ -361,16 +873,1311 @@ pub fn not(
     })
 }
 
-/// Computes `-a`.
+/// Computes `-a`, wrapping around at the boundary of an 64-bit integer.
 #[napi]
 pub fn negative(
     env: Env,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] a: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+
+    Ok(Int64 {
+        v: a.wrapping_neg()
+    })
+}
+
+/// Computes `|a|`, wrapping around at the boundary of an 64-bit integer.
+#[napi]
+pub fn abs(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+
+    Ok(Int64 {
+        v: a.wrapping_abs()
+    })
+}
+
+/// Computes `|a|` without overflow, as a 64-bit unsigned integer.
+#[napi(js_name = "unsignedAbs")]
+pub fn unsigned_abs(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<UInt64> {
+    let a = to_i64(&env, a)?;
+
+    Ok(UInt64 {
+        v: a.unsigned_abs()
+    })
+}
+
+/// Computes `|a - b|` without overflow, as a 64-bit unsigned integer.
+#[napi(js_name = "absDiff")]
+pub fn abs_diff(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<UInt64> {
+    let a = to_i64(&env, a)?;
+    let b = to_i64(&env, b)?;
+
+    Ok(UInt64 {
+        v: a.abs_diff(b)
+    })
+}
+
+/// Computes `sign(a)`, which is `-1`, `0` or `1`.
+#[napi]
+pub fn signum(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+
+    Ok(Int64 {
+        v: a.signum()
+    })
+}
+
+/// Computes `a > 0`.
+#[napi(js_name = "isPositive")]
+pub fn is_positive(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<bool> {
+    let a = to_i64(&env, a)?;
+
+    Ok(a > 0)
+}
+
+/// Computes `a < 0`.
+#[napi(js_name = "isNegative")]
+pub fn is_negative(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<bool> {
+    let a = to_i64(&env, a)?;
+
+    Ok(a < 0)
+}
+
+/// Computes `a === 0`.
+#[napi(js_name = "isZero")]
+pub fn is_zero(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<bool> {
+    let a = to_i64(&env, a)?;
+
+    Ok(a == 0)
+}
+
+/// Computes `gcd(a, b)`, throwing a RangeError if overflow occurred. The result is never negative.
+#[napi]
+pub fn gcd(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+    let b = to_i64(&env, b)?;
+
+    match checked_gcd(a, b) {
+        Some(v) => Ok(Int64 {
+            v,
+        }),
+        None => {
+            env.throw_range_error(
+                &format!(
+                    "attempt to calculate the greatest common divisor of {a} and {b} with overflow"
+                ),
+                Some(ErrorCode::Overflow.as_str()),
+            )?;
+
+            Err(Error::from_reason(""))
+        },
+    }
+}
+
+/// Computes `lcm(a, b)`, throwing a RangeError if overflow occurred. The result is never negative.
+#[napi]
+pub fn lcm(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+    let b = to_i64(&env, b)?;
+
+    match checked_lcm(a, b) {
+        Some(v) => Ok(Int64 {
+            v,
+        }),
+        None => {
+            env.throw_range_error(
+                &format!(
+                    "attempt to calculate the least common multiple of {a} and {b} with overflow"
+                ),
+                Some(ErrorCode::Overflow.as_str()),
+            )?;
+
+            Err(Error::from_reason(""))
+        },
+    }
+}
+
+/// Computes `floor(sqrt(a))`.
+///
+/// `a` must not be smaller than zero
+#[napi]
+pub fn isqrt(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
 ) -> Result<Int64> {
     let a = to_i64(&env, a)?;
 
+    if a < 0 {
+        env.throw_range_error(
+            &format!(
+                "the radicand of an integer square root must not be smaller than zero, but it is \
+                 {a}"
+            ),
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    }
+
     Ok(Int64 {
-        v: -a
+        v: isqrt_u64(a as u64) as i64
+    })
+}
+
+/// Computes `floor(log2(a))`.
+///
+/// `a` must be bigger than zero
+#[napi]
+pub fn ilog2(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<u32> {
+    let a = to_i64(&env, a)?;
+
+    if a <= 0 {
+        env.throw_range_error(
+            &format!(
+                "the argument of an integer logarithm must be bigger than zero, but it is {a}"
+            ),
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    }
+
+    Ok(ilog_u64(a as u64, 2))
+}
+
+/// Computes `floor(log10(a))`.
+///
+/// `a` must be bigger than zero
+#[napi]
+pub fn ilog10(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<u32> {
+    let a = to_i64(&env, a)?;
+
+    if a <= 0 {
+        env.throw_range_error(
+            &format!(
+                "the argument of an integer logarithm must be bigger than zero, but it is {a}"
+            ),
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    }
+
+    Ok(ilog_u64(a as u64, 10))
+}
+
+/// Computes `floor(log(a) / log(base))`.
+///
+/// `a` must be bigger than zero, and `base` must not be smaller than 2
+#[napi]
+pub fn ilog(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] base: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<u32> {
+    let a = to_i64(&env, a)?;
+    let b = to_i64(&env, base)?;
+
+    if a <= 0 {
+        env.throw_range_error(
+            &format!(
+                "the argument of an integer logarithm must be bigger than zero, but it is {a}"
+            ),
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    } else if b < 2 {
+        env.throw_range_error(
+            &format!("the base of an integer logarithm must not be smaller than 2, but it is {b}"),
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    }
+
+    Ok(ilog_u64(a as u64, b as u64))
+}
+
+/// Computes `(a + b) / 2` without overflow, rounding towards zero.
+#[napi]
+pub fn midpoint(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+    let b = to_i64(&env, b)?;
+
+    Ok(Int64 {
+        v: midpoint_i64(a, b)
+    })
+}
+
+/// Computes `(a + b) mod m` without overflow. The result is between `0` and `m - 1`.
+///
+/// `m` must be bigger than zero
+#[napi(js_name = "addMod")]
+pub fn add_mod(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] m: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+    let b = to_i64(&env, b)?;
+    let m = check_modulus(&env, m)?;
+
+    Ok(Int64 {
+        v: add_mod_i64(a, b, m)
+    })
+}
+
+/// Computes `(a * b) mod m` without overflow. The result is between `0` and `m - 1`.
+///
+/// `m` must be bigger than zero
+#[napi(js_name = "mulMod")]
+pub fn mul_mod(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] m: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+    let b = to_i64(&env, b)?;
+    let m = check_modulus(&env, m)?;
+
+    Ok(Int64 {
+        v: mul_mod_i64(a, b, m)
+    })
+}
+
+/// Computes `(a ^ b) mod m` without overflow. The result is between `0` and `m - 1`.
+///
+/// `b` must not be smaller than zero, and `m` must be bigger than zero
+#[napi(js_name = "powMod")]
+pub fn pow_mod(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] m: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+    let b = to_i64(&env, b)?;
+    let m = check_modulus(&env, m)?;
+
+    if b < 0 {
+        env.throw_range_error(
+            "the exponent of an integer number must not be smaller than zero",
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    }
+
+    Ok(Int64 {
+        v: pow_mod_i64(a, b as u64, m)
+    })
+}
+
+/// Computes `a^-1 mod m`, the number `x` between `0` and `m - 1` which makes `(a * x) mod m` equal `1`, throwing a RangeError if it does not exist.
+///
+/// `m` must be bigger than zero
+#[napi(js_name = "modInverse")]
+pub fn mod_inverse(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] m: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+    let m = check_modulus(&env, m)?;
+
+    match mod_inverse_i64(a, m) {
+        Some(v) => Ok(Int64 {
+            v,
+        }),
+        None => {
+            env.throw_range_error(
+                &format!("the modular inverse of {a} modulo {m} does not exist"),
+                Some(ErrorCode::NotInvertible.as_str()),
+            )?;
+
+            Err(Error::from_reason(""))
+        },
+    }
+}
+
+/// Tests whether `a` is a prime number. The test is deterministic.
+#[napi(js_name = "isPrime")]
+pub fn is_prime(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<bool> {
+    let a = to_i64(&env, a)?;
+
+    Ok(a > 1 && is_prime_u64(a as u64))
+}
+
+/// Finds the smallest prime number bigger than `a`, throwing a RangeError if overflow occurred.
+#[napi(js_name = "nextPrime")]
+pub fn next_prime(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+
+    match next_prime_i64(a) {
+        Some(v) => Ok(Int64 {
+            v,
+        }),
+        None => {
+            env.throw_range_error(
+                &format!("attempt to find the next prime number after {a} with overflow"),
+                Some(ErrorCode::Overflow.as_str()),
+            )?;
+
+            Err(Error::from_reason(""))
+        },
+    }
+}
+
+/// Finds the biggest prime number smaller than `a`.
+///
+/// `a` must be bigger than 2
+#[napi(js_name = "prevPrime")]
+pub fn prev_prime(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+
+    match prev_prime_i64(a) {
+        Some(v) => Ok(Int64 {
+            v,
+        }),
+        None => {
+            env.throw_range_error(
+                &format!("there is no prime number smaller than {a}"),
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            Err(Error::from_reason(""))
+        },
+    }
+}
+
+/// Computes the prime factors of `a` with multiplicities, in ascending order. The factors of `1` is an empty array.
+///
+/// `a` must be bigger than zero
+#[napi]
+pub fn factorize(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Vec<Int64>> {
+    let a = to_i64(&env, a)?;
+
+    if a <= 0 {
+        env.throw_range_error(
+            &format!("the number to factorize must be bigger than zero, but it is {a}"),
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    }
+
+    Ok(factorize_u64(a as u64)
+        .into_iter()
+        .map(|v| Int64 {
+            v: v as i64
+        })
+        .collect())
+}
+
+/// Counts the number of ones in the binary representation of `a`.
+#[napi(js_name = "countOnes")]
+pub fn count_ones(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<u32> {
+    let a = to_i64(&env, a)?;
+
+    Ok(a.count_ones())
+}
+
+/// Counts the number of zeros in the binary representation of `a`.
+#[napi(js_name = "countZeros")]
+pub fn count_zeros(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<u32> {
+    let a = to_i64(&env, a)?;
+
+    Ok(a.count_zeros())
+}
+
+/// Counts the number of leading zeros in the binary representation of `a`.
+#[napi(js_name = "leadingZeros")]
+pub fn leading_zeros(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<u32> {
+    let a = to_i64(&env, a)?;
+
+    Ok(a.leading_zeros())
+}
+
+/// Counts the number of trailing zeros in the binary representation of `a`.
+#[napi(js_name = "trailingZeros")]
+pub fn trailing_zeros(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<u32> {
+    let a = to_i64(&env, a)?;
+
+    Ok(a.trailing_zeros())
+}
+
+/// Counts the number of leading ones in the binary representation of `a`.
+#[napi(js_name = "leadingOnes")]
+pub fn leading_ones(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<u32> {
+    let a = to_i64(&env, a)?;
+
+    Ok(a.leading_ones())
+}
+
+/// Counts the number of trailing ones in the binary representation of `a`.
+#[napi(js_name = "trailingOnes")]
+pub fn trailing_ones(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<u32> {
+    let a = to_i64(&env, a)?;
+
+    Ok(a.trailing_ones())
+}
+
+/// Counts the number of bits needed to represent `a` in two's complement, excluding the sign bit. It is `0` for `0` and `-1`.
+#[napi(js_name = "bitLength")]
+pub fn bit_length(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<u32> {
+    let a = to_i64(&env, a)?;
+
+    Ok(bit_length_i64(a))
+}
+
+/// Tests whether `a` is a power of two.
+#[napi(js_name = "isPowerOfTwo")]
+pub fn is_power_of_two(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<bool> {
+    let a = to_i64(&env, a)?;
+
+    Ok(a > 0 && a & (a - 1) == 0)
+}
+
+/// Computes the smallest power of two which is not smaller than `a`, throwing a RangeError if overflow occurred. It is `1` if `a` is not bigger than `1`.
+#[napi(js_name = "nextPowerOfTwo")]
+pub fn next_power_of_two(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+
+    match checked_next_power_of_two(a) {
+        Some(v) => Ok(Int64 {
+            v,
+        }),
+        None => {
+            env.throw_range_error(
+                &format!("attempt to calculate the next power of two of {a} with overflow"),
+                Some(ErrorCode::Overflow.as_str()),
+            )?;
+
+            Err(Error::from_reason(""))
+        },
+    }
+}
+
+/// Computes `a + b`, throwing a RangeError if overflow occurred.
+#[napi(js_name = "checkedAdd")]
+pub fn checked_add(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+    let b = to_i64(&env, b)?;
+
+    match a.checked_add(b) {
+        Some(v) => Ok(Int64 {
+            v,
+        }),
+        None => {
+            env.throw_range_error(
+                &format!("attempt to add {a} and {b} with overflow"),
+                Some(ErrorCode::Overflow.as_str()),
+            )?;
+
+            Err(Error::from_reason(""))
+        },
+    }
+}
+
+/// Computes `a - b`, throwing a RangeError if overflow occurred.
+#[napi(js_name = "checkedSubtract")]
+pub fn checked_subtract(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+    let b = to_i64(&env, b)?;
+
+    match a.checked_sub(b) {
+        Some(v) => Ok(Int64 {
+            v,
+        }),
+        None => {
+            env.throw_range_error(
+                &format!("attempt to subtract {b} from {a} with overflow"),
+                Some(ErrorCode::Overflow.as_str()),
+            )?;
+
+            Err(Error::from_reason(""))
+        },
+    }
+}
+
+/// Computes `a * b`, throwing a RangeError if overflow occurred.
+#[napi(js_name = "checkedMultiply")]
+pub fn checked_multiply(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+    let b = to_i64(&env, b)?;
+
+    match a.checked_mul(b) {
+        Some(v) => Ok(Int64 {
+            v,
+        }),
+        None => {
+            env.throw_range_error(
+                &format!("attempt to multiply {a} by {b} with overflow"),
+                Some(ErrorCode::Overflow.as_str()),
+            )?;
+
+            Err(Error::from_reason(""))
+        },
+    }
+}
+
+/// Computes `a / b`, throwing a RangeError if overflow occurred.
+#[napi(js_name = "checkedDivide")]
+pub fn checked_divide(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+    let input = b.raw_input();
+    let b = to_i64(&env, b)?;
+
+    if b == 0 {
+        return throw_divide_by_zero(
+            &env,
+            format!("attempt to divide {a} by zero"),
+            input,
+            Int64 {
+                v: b
+            },
+        );
+    }
+
+    match a.checked_div(b) {
+        Some(v) => Ok(Int64 {
+            v,
+        }),
+        None => {
+            env.throw_range_error(
+                &format!("attempt to divide {a} by {b} with overflow"),
+                Some(ErrorCode::Overflow.as_str()),
+            )?;
+
+            Err(Error::from_reason(""))
+        },
+    }
+}
+
+/// Computes `a ^ b`, throwing a RangeError if overflow occurred.
+///
+/// `b` must not be smaller than zero
+#[napi(js_name = "checkedPow")]
+pub fn checked_pow(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+    let b = to_i64(&env, b)?;
+
+    if b < 0 {
+        env.throw_range_error(
+            "the exponent of an integer number must not be smaller than zero",
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    } else if b > u32::MAX as i64 {
+        env.throw_range_error(
+            &format!("the exponent of an integer number must not be bigger than {}", u32::MAX),
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    }
+
+    match a.checked_pow(b as u32) {
+        Some(v) => Ok(Int64 {
+            v,
+        }),
+        None => {
+            env.throw_range_error(
+                &format!("attempt to raise {a} to the power of {b} with overflow"),
+                Some(ErrorCode::Overflow.as_str()),
+            )?;
+
+            Err(Error::from_reason(""))
+        },
+    }
+}
+
+/// Computes `a << b`, throwing a RangeError if overflow occurred.
+///
+/// Unlike `shiftLeft`, the bit count is not masked, and the shift fails if any set bit (or the sign) would be shifted out.
+#[napi(js_name = "checkedShl")]
+pub fn checked_shift_left(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+    let b = to_i64(&env, b)?;
+
+    if b < 0 {
+        env.throw_range_error(
+            "the bit count for shift must not be smaller than zero",
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    } else if b > u32::MAX as i64 {
+        env.throw_range_error(
+            &format!("the bit count for shift must not be bigger than {}", u32::MAX),
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    }
+
+    match checked_shl(a, b as u32) {
+        Some(v) => Ok(Int64 {
+            v,
+        }),
+        None => {
+            env.throw_range_error(
+                &format!("attempt to shift {a} left by {b} with overflow"),
+                Some(ErrorCode::Overflow.as_str()),
+            )?;
+
+            Err(Error::from_reason(""))
+        },
+    }
+}
+
+/// Computes `-a`, throwing a RangeError if overflow occurred.
+#[napi(js_name = "checkedNeg")]
+pub fn checked_negative(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+
+    match a.checked_neg() {
+        Some(v) => Ok(Int64 {
+            v,
+        }),
+        None => {
+            env.throw_range_error(
+                &format!("attempt to negate {a} with overflow"),
+                Some(ErrorCode::Overflow.as_str()),
+            )?;
+
+            Err(Error::from_reason(""))
+        },
+    }
+}
+
+/// Computes `a + b`, saturating at the boundary of an 64-bit integer.
+#[napi(js_name = "saturatingAdd")]
+pub fn saturating_add(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+    let b = to_i64(&env, b)?;
+
+    Ok(Int64 {
+        v: a.saturating_add(b)
+    })
+}
+
+/// Computes `a - b`, saturating at the boundary of an 64-bit integer.
+#[napi(js_name = "saturatingSubtract")]
+pub fn saturating_subtract(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+    let b = to_i64(&env, b)?;
+
+    Ok(Int64 {
+        v: a.saturating_sub(b)
+    })
+}
+
+/// Computes `a * b`, saturating at the boundary of an 64-bit integer.
+#[napi(js_name = "saturatingMultiply")]
+pub fn saturating_multiply(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+    let b = to_i64(&env, b)?;
+
+    Ok(Int64 {
+        v: a.saturating_mul(b)
+    })
+}
+
+/// Computes `a ^ b`, saturating at the boundary of an 64-bit integer.
+///
+/// `b` must not be smaller than zero
+#[napi(js_name = "saturatingPow")]
+pub fn saturating_pow(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+    let b = to_i64(&env, b)?;
+
+    if b < 0 {
+        env.throw_range_error(
+            "the exponent of an integer number must not be smaller than zero",
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    } else if b > u32::MAX as i64 {
+        env.throw_range_error(
+            &format!("the exponent of an integer number must not be bigger than {}", u32::MAX),
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    }
+
+    Ok(Int64 {
+        v: a.saturating_pow(b as u32)
+    })
+}
+
+/// Computes `-a`, saturating at the boundary of an 64-bit integer.
+#[napi(js_name = "saturatingNeg")]
+pub fn saturating_negative(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Int64> {
+    let a = to_i64(&env, a)?;
+
+    Ok(Int64 {
+        v: a.saturating_neg()
+    })
+}
+
+/// Computes `a + b`, wrapping around at the boundary of an 64-bit integer, and returns the result along with whether an overflow occurred.
+#[napi(js_name = "overflowingAdd")]
+pub fn overflowing_add(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<OverflowingResult> {
+    let a = to_i64(&env, a)?;
+    let b = to_i64(&env, b)?;
+
+    let (v, overflowed) = a.overflowing_add(b);
+
+    Ok(OverflowingResult {
+        value: Int64 {
+            v,
+        },
+        overflowed,
+    })
+}
+
+/// Computes `a - b`, wrapping around at the boundary of an 64-bit integer, and returns the result along with whether an overflow occurred.
+#[napi(js_name = "overflowingSubtract")]
+pub fn overflowing_subtract(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<OverflowingResult> {
+    let a = to_i64(&env, a)?;
+    let b = to_i64(&env, b)?;
+
+    let (v, overflowed) = a.overflowing_sub(b);
+
+    Ok(OverflowingResult {
+        value: Int64 {
+            v,
+        },
+        overflowed,
+    })
+}
+
+/// Computes `a * b`, wrapping around at the boundary of an 64-bit integer, and returns the result along with whether an overflow occurred.
+#[napi(js_name = "overflowingMultiply")]
+pub fn overflowing_multiply(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<OverflowingResult> {
+    let a = to_i64(&env, a)?;
+    let b = to_i64(&env, b)?;
+
+    let (v, overflowed) = a.overflowing_mul(b);
+
+    Ok(OverflowingResult {
+        value: Int64 {
+            v,
+        },
+        overflowed,
+    })
+}
+
+/// Computes `a ^ b`, wrapping around at the boundary of an 64-bit integer, and returns the result along with whether an overflow occurred.
+///
+/// `b` must not be smaller than zero
+#[napi(js_name = "overflowingPow")]
+pub fn overflowing_pow(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<OverflowingResult> {
+    let a = to_i64(&env, a)?;
+    let b = to_i64(&env, b)?;
+
+    if b < 0 {
+        env.throw_range_error(
+            "the exponent of an integer number must not be smaller than zero",
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    } else if b > u32::MAX as i64 {
+        env.throw_range_error(
+            &format!("the exponent of an integer number must not be bigger than {}", u32::MAX),
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    }
+
+    let (v, overflowed) = a.overflowing_pow(b as u32);
+
+    Ok(OverflowingResult {
+        value: Int64 {
+            v,
+        },
+        overflowed,
+    })
+}
+
+/// Computes `a << b`, wrapping around at the boundary of an 64-bit integer, and returns the result along with whether an overflow occurred.
+///
+/// `b` must not be smaller than zero. The overflow flag is set if `b` is not smaller than 64, in which case the bit count is masked to 6 bits.
+#[napi(js_name = "overflowingShl")]
+pub fn overflowing_shift_left(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<OverflowingResult> {
+    let a = to_i64(&env, a)?;
+    let b = to_i64(&env, b)?;
+
+    if b < 0 {
+        env.throw_range_error(
+            "the bit count for shift must not be smaller than zero",
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    } else if b > u32::MAX as i64 {
+        env.throw_range_error(
+            &format!("the bit count for shift must not be bigger than {}", u32::MAX),
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    }
+
+    let (v, overflowed) = a.overflowing_shl(b as u32);
+
+    Ok(OverflowingResult {
+        value: Int64 {
+            v,
+        },
+        overflowed,
+    })
+}
+
+/// Computes `a >> b`, wrapping around at the boundary of an 64-bit integer, and returns the result along with whether an overflow occurred.
+///
+/// `b` must not be smaller than zero. The overflow flag is set if `b` is not smaller than 64, in which case the bit count is masked to 6 bits.
+#[napi(js_name = "overflowingShr")]
+pub fn overflowing_shift_right(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<OverflowingResult> {
+    let a = to_i64(&env, a)?;
+    let b = to_i64(&env, b)?;
+
+    if b < 0 {
+        env.throw_range_error(
+            "the bit count for shift must not be smaller than zero",
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    } else if b > u32::MAX as i64 {
+        env.throw_range_error(
+            &format!("the bit count for shift must not be bigger than {}", u32::MAX),
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    }
+
+    let (v, overflowed) = a.overflowing_shr(b as u32);
+
+    Ok(OverflowingResult {
+        value: Int64 {
+            v,
+        },
+        overflowed,
+    })
+}
+
+/// Computes `-a`, wrapping around at the boundary of an 64-bit integer, and returns the result along with whether an overflow occurred.
+#[napi(js_name = "overflowingNeg")]
+pub fn overflowing_negative(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<OverflowingResult> {
+    let a = to_i64(&env, a)?;
+
+    let (v, overflowed) = a.overflowing_neg();
+
+    Ok(OverflowingResult {
+        value: Int64 {
+            v,
+        },
+        overflowed,
     })
 }
 
@@ -378,8 +2185,14 @@ pub fn negative(
 #[napi]
 pub fn eq(
     env: Env,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] a: Either<&Int64, Unknown>,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
 ) -> Result<bool> {
     let a = to_i64(&env, a)?;
     let b = to_i64(&env, b)?;
@@ -391,8 +2204,14 @@ pub fn eq(
 #[napi]
 pub fn ne(
     env: Env,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] a: Either<&Int64, Unknown>,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
 ) -> Result<bool> {
     let a = to_i64(&env, a)?;
     let b = to_i64(&env, b)?;
@@ -404,8 +2223,14 @@ pub fn ne(
 #[napi]
 pub fn gt(
     env: Env,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] a: Either<&Int64, Unknown>,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
 ) -> Result<bool> {
     let a = to_i64(&env, a)?;
     let b = to_i64(&env, b)?;
@@ -417,8 +2242,14 @@ pub fn gt(
 #[napi]
 pub fn gte(
     env: Env,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] a: Either<&Int64, Unknown>,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
 ) -> Result<bool> {
     let a = to_i64(&env, a)?;
     let b = to_i64(&env, b)?;
@@ -430,8 +2261,14 @@ pub fn gte(
 #[napi]
 pub fn lt(
     env: Env,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] a: Either<&Int64, Unknown>,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
 ) -> Result<bool> {
     let a = to_i64(&env, a)?;
     let b = to_i64(&env, b)?;
@@ -443,8 +2280,14 @@ pub fn lt(
 #[napi]
 pub fn lte(
     env: Env,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] a: Either<&Int64, Unknown>,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
 ) -> Result<bool> {
     let a = to_i64(&env, a)?;
     let b = to_i64(&env, b)?;
@@ -458,8 +2301,14 @@ pub fn lte(
 #[napi]
 pub fn comp(
     env: Env,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] a: Either<&Int64, Unknown>,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
 ) -> Result<Ordering> {
     let a = to_i64(&env, a)?;
     let b = to_i64(&env, b)?;
@@ -471,8 +2320,14 @@ pub fn comp(
 #[napi]
 pub fn random(
     env: Env,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] a: Either<&Int64, Unknown>,
-    #[napi(ts_arg_type = "number | string | Buffer | Int64")] b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
 ) -> Result<Int64> {
     let a = to_i64(&env, a)?;
     let b = to_i64(&env, b)?;
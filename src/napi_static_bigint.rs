@@ -0,0 +1,947 @@
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+use crate::{napi_static, Int64, ParseOptions, ShiftMode};
+
+/// Parses a string as a 64-bit integer.
+///
+/// @param options A radix between 2 and 36, or the parsing options. If the radix is not set, it is decided by the `0b`, `0o` or `0x` prefix of the string, or is 10 without any prefix.
+#[napi(namespace = "bigint")]
+pub fn parse(
+    env: Env,
+    value: String,
+    #[napi(ts_arg_type = "number | ParseOptions")] options: Option<Either<u32, ParseOptions>>,
+) -> Result<BigInt> {
+    napi_static::parse(env, value, options).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `a + b`, wrapping around at the boundary of an 64-bit integer.
+#[napi(namespace = "bigint")]
+pub fn add(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::add(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `a - b`, wrapping around at the boundary of an 64-bit integer.
+#[napi(namespace = "bigint")]
+pub fn subtract(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::subtract(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `a * b`, wrapping around at the boundary of an 64-bit integer.
+#[napi(namespace = "bigint")]
+pub fn multiply(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::multiply(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `a / b`, wrapping around at the boundary of an 64-bit integer.
+#[napi(namespace = "bigint")]
+pub fn divide(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::divide(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `a % b`, wrapping around at the boundary of an 64-bit integer.
+#[napi(namespace = "bigint", js_name = "mod")]
+pub fn modulo(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::modulo(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes the Euclidean quotient of `a / b`, wrapping around at the boundary of an 64-bit integer. The remainder of the Euclidean division is never negative.
+#[napi(namespace = "bigint", js_name = "divEuclid")]
+pub fn div_euclid(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::div_euclid(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes the least non-negative remainder of `a / b`, wrapping around at the boundary of an 64-bit integer.
+#[napi(namespace = "bigint", js_name = "remEuclid")]
+pub fn rem_euclid(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::rem_euclid(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes the quotient of `a / b` rounded towards negative infinity, wrapping around at the boundary of an 64-bit integer.
+#[napi(namespace = "bigint", js_name = "divFloor")]
+pub fn div_floor(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::div_floor(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes the remainder of `a / b` with the quotient rounded towards negative infinity, wrapping around at the boundary of an 64-bit integer. The remainder has the same sign as `b`.
+#[napi(namespace = "bigint", js_name = "modFloor")]
+pub fn mod_floor(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::mod_floor(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes the quotient of `a / b` rounded towards positive infinity, wrapping around at the boundary of an 64-bit integer.
+#[napi(namespace = "bigint", js_name = "divCeil")]
+pub fn div_ceil(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::div_ceil(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `a ^ b`, wrapping around at the boundary of an 64-bit integer.
+///
+/// `b` must not be smaller than zero
+#[napi(namespace = "bigint")]
+pub fn pow(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::pow(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `a << b`, wrapping around at the boundary of an 64-bit integer.
+///
+/// `b` must not be smaller than zero
+///
+/// @param mode How a bit count which is not smaller than 64 is treated. Default: `"mask"`.
+#[napi(namespace = "bigint", js_name = "shiftLeft")]
+pub fn shift_left(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+    mode: Option<ShiftMode>,
+) -> Result<BigInt> {
+    napi_static::shift_left(env, a, b, mode).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `a >> b`, wrapping around at the boundary of an 64-bit integer.
+///
+/// `b` must not be smaller than zero
+///
+/// @param mode How a bit count which is not smaller than 64 is treated. Default: `"mask"`.
+#[napi(namespace = "bigint", js_name = "shiftRight")]
+pub fn shift_right(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+    mode: Option<ShiftMode>,
+) -> Result<BigInt> {
+    napi_static::shift_right(env, a, b, mode).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `a >>> b`, wrapping around at the boundary of an 64-bit integer.
+///
+/// `b` must not be smaller than zero
+///
+/// @param mode How a bit count which is not smaller than 64 is treated. Default: `"mask"`.
+#[napi(namespace = "bigint", js_name = "shiftRightUnsigned")]
+pub fn shift_right_unsigned(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+    mode: Option<ShiftMode>,
+) -> Result<BigInt> {
+    napi_static::shift_right_unsigned(env, a, b, mode).map(|n| BigInt::from(n.v))
+}
+
+/// Shifts the bits to the left by a specified amount n, wrapping the truncated bits to the beginning of the resulting 64-bit integer.
+///
+/// `b` must not be smaller than zero
+///
+/// @param mode How a bit count which is not smaller than 64 is treated. Default: `"mask"`.
+#[napi(namespace = "bigint", js_name = "rotateLeft")]
+pub fn rotate_left(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+    mode: Option<ShiftMode>,
+) -> Result<BigInt> {
+    napi_static::rotate_left(env, a, b, mode).map(|n| BigInt::from(n.v))
+}
+
+/// Shifts the bits to the right by a specified amount n, wrapping the truncated bits to the beginning of the resulting 64-bit integer.
+///
+/// `b` must not be smaller than zero
+///
+/// @param mode How a bit count which is not smaller than 64 is treated. Default: `"mask"`.
+#[napi(namespace = "bigint", js_name = "rotateRight")]
+pub fn rotate_right(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+    mode: Option<ShiftMode>,
+) -> Result<BigInt> {
+    napi_static::rotate_right(env, a, b, mode).map(|n| BigInt::from(n.v))
+}
+
+/// Reverses the byte order of `a`.
+#[napi(namespace = "bigint", js_name = "swapBytes")]
+pub fn swap_bytes(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::swap_bytes(env, a).map(|n| BigInt::from(n.v))
+}
+
+/// Reverses the order of the bits of `a`. The least significant bit becomes the most significant bit.
+#[napi(namespace = "bigint", js_name = "reverseBits")]
+pub fn reverse_bits(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::reverse_bits(env, a).map(|n| BigInt::from(n.v))
+}
+
+/// Converts `a` from the native byte order to Big-Endian. On Little-Endian platforms the bytes are swapped, otherwise nothing happens.
+#[napi(namespace = "bigint", js_name = "toBigEndian")]
+pub fn to_big_endian(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::to_big_endian(env, a).map(|n| BigInt::from(n.v))
+}
+
+/// Converts `a` from Big-Endian to the native byte order. On Little-Endian platforms the bytes are swapped, otherwise nothing happens.
+#[napi(namespace = "bigint", js_name = "fromBigEndian")]
+pub fn from_big_endian(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::from_big_endian(env, a).map(|n| BigInt::from(n.v))
+}
+
+/// Converts `a` from the native byte order to Little-Endian. On Big-Endian platforms the bytes are swapped, otherwise nothing happens.
+#[napi(namespace = "bigint", js_name = "toLittleEndian")]
+pub fn to_little_endian(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::to_little_endian(env, a).map(|n| BigInt::from(n.v))
+}
+
+/// Converts `a` from Little-Endian to the native byte order. On Big-Endian platforms the bytes are swapped, otherwise nothing happens.
+#[napi(namespace = "bigint", js_name = "fromLittleEndian")]
+pub fn from_little_endian(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::from_little_endian(env, a).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `a & b`.
+#[napi(namespace = "bigint")]
+pub fn and(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::and(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `a | b`.
+#[napi(namespace = "bigint")]
+pub fn or(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::or(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `a ^ b`.
+#[napi(namespace = "bigint")]
+pub fn xor(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::xor(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `~(a & b)`.
+#[napi(namespace = "bigint")]
+pub fn nand(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::nand(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `~(a | b)`.
+#[napi(namespace = "bigint")]
+pub fn nor(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::nor(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `~(a ^ b)`.
+#[napi(namespace = "bigint")]
+pub fn xnor(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::xnor(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `~a`.
+#[napi(namespace = "bigint")]
+pub fn not(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::not(env, a).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `-a`, wrapping around at the boundary of an 64-bit integer.
+#[napi(namespace = "bigint")]
+pub fn negative(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::negative(env, a).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `|a|`, wrapping around at the boundary of an 64-bit integer.
+#[napi(namespace = "bigint")]
+pub fn abs(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::abs(env, a).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `|a|` without overflow, as a 64-bit unsigned integer.
+#[napi(namespace = "bigint", js_name = "unsignedAbs")]
+pub fn unsigned_abs(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::unsigned_abs(env, a).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `|a - b|` without overflow, as a 64-bit unsigned integer.
+#[napi(namespace = "bigint", js_name = "absDiff")]
+pub fn abs_diff(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::abs_diff(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `sign(a)`, which is `-1`, `0` or `1`.
+#[napi(namespace = "bigint")]
+pub fn signum(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::signum(env, a).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `gcd(a, b)`, throwing a RangeError if overflow occurred. The result is never negative.
+#[napi(namespace = "bigint")]
+pub fn gcd(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::gcd(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `lcm(a, b)`, throwing a RangeError if overflow occurred. The result is never negative.
+#[napi(namespace = "bigint")]
+pub fn lcm(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::lcm(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `floor(sqrt(a))`.
+///
+/// `a` must not be smaller than zero
+#[napi(namespace = "bigint")]
+pub fn isqrt(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::isqrt(env, a).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `(a + b) / 2` without overflow, rounding towards zero.
+#[napi(namespace = "bigint")]
+pub fn midpoint(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::midpoint(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `(a + b) mod m` without overflow. The result is between `0` and `m - 1`.
+///
+/// `m` must be bigger than zero
+#[napi(namespace = "bigint", js_name = "addMod")]
+pub fn add_mod(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] m: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::add_mod(env, a, b, m).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `(a * b) mod m` without overflow. The result is between `0` and `m - 1`.
+///
+/// `m` must be bigger than zero
+#[napi(namespace = "bigint", js_name = "mulMod")]
+pub fn mul_mod(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] m: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::mul_mod(env, a, b, m).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `(a ^ b) mod m` without overflow. The result is between `0` and `m - 1`.
+///
+/// `b` must not be smaller than zero, and `m` must be bigger than zero
+#[napi(namespace = "bigint", js_name = "powMod")]
+pub fn pow_mod(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] m: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::pow_mod(env, a, b, m).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `a^-1 mod m`, the number `x` between `0` and `m - 1` which makes `(a * x) mod m` equal `1`, throwing a RangeError if it does not exist.
+///
+/// `m` must be bigger than zero
+#[napi(namespace = "bigint", js_name = "modInverse")]
+pub fn mod_inverse(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] m: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::mod_inverse(env, a, m).map(|n| BigInt::from(n.v))
+}
+
+/// Finds the smallest prime number bigger than `a`, throwing a RangeError if overflow occurred.
+#[napi(namespace = "bigint", js_name = "nextPrime")]
+pub fn next_prime(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::next_prime(env, a).map(|n| BigInt::from(n.v))
+}
+
+/// Finds the biggest prime number smaller than `a`.
+///
+/// `a` must be bigger than 2
+#[napi(namespace = "bigint", js_name = "prevPrime")]
+pub fn prev_prime(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::prev_prime(env, a).map(|n| BigInt::from(n.v))
+}
+
+/// Computes the prime factors of `a` with multiplicities, in ascending order. The factors of `1` is an empty array.
+///
+/// `a` must be bigger than zero
+#[napi(namespace = "bigint")]
+pub fn factorize(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<Vec<BigInt>> {
+    napi_static::factorize(env, a).map(|v| v.into_iter().map(|n| BigInt::from(n.v)).collect())
+}
+
+/// Computes the smallest power of two which is not smaller than `a`, throwing a RangeError if overflow occurred. It is `1` if `a` is not bigger than `1`.
+#[napi(namespace = "bigint", js_name = "nextPowerOfTwo")]
+pub fn next_power_of_two(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::next_power_of_two(env, a).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `a + b`, throwing a RangeError if overflow occurred.
+#[napi(namespace = "bigint", js_name = "checkedAdd")]
+pub fn checked_add(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::checked_add(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `a - b`, throwing a RangeError if overflow occurred.
+#[napi(namespace = "bigint", js_name = "checkedSubtract")]
+pub fn checked_subtract(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::checked_subtract(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `a * b`, throwing a RangeError if overflow occurred.
+#[napi(namespace = "bigint", js_name = "checkedMultiply")]
+pub fn checked_multiply(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::checked_multiply(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `a / b`, throwing a RangeError if overflow occurred.
+#[napi(namespace = "bigint", js_name = "checkedDivide")]
+pub fn checked_divide(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::checked_divide(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `a ^ b`, throwing a RangeError if overflow occurred.
+///
+/// `b` must not be smaller than zero
+#[napi(namespace = "bigint", js_name = "checkedPow")]
+pub fn checked_pow(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::checked_pow(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `a << b`, throwing a RangeError if overflow occurred.
+///
+/// Unlike `shiftLeft`, the bit count is not masked, and the shift fails if any set bit (or the sign) would be shifted out.
+#[napi(namespace = "bigint", js_name = "checkedShl")]
+pub fn checked_shift_left(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::checked_shift_left(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `-a`, throwing a RangeError if overflow occurred.
+#[napi(namespace = "bigint", js_name = "checkedNeg")]
+pub fn checked_negative(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::checked_negative(env, a).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `a + b`, saturating at the boundary of an 64-bit integer.
+#[napi(namespace = "bigint", js_name = "saturatingAdd")]
+pub fn saturating_add(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::saturating_add(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `a - b`, saturating at the boundary of an 64-bit integer.
+#[napi(namespace = "bigint", js_name = "saturatingSubtract")]
+pub fn saturating_subtract(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::saturating_subtract(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `a * b`, saturating at the boundary of an 64-bit integer.
+#[napi(namespace = "bigint", js_name = "saturatingMultiply")]
+pub fn saturating_multiply(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::saturating_multiply(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `a ^ b`, saturating at the boundary of an 64-bit integer.
+///
+/// `b` must not be smaller than zero
+#[napi(namespace = "bigint", js_name = "saturatingPow")]
+pub fn saturating_pow(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::saturating_pow(env, a, b).map(|n| BigInt::from(n.v))
+}
+
+/// Computes `-a`, saturating at the boundary of an 64-bit integer.
+#[napi(namespace = "bigint", js_name = "saturatingNeg")]
+pub fn saturating_negative(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::saturating_negative(env, a).map(|n| BigInt::from(n.v))
+}
+
+/// Gets a random 64-bit integer between `a` and `b`.
+#[napi(namespace = "bigint")]
+pub fn random(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] a: Either<
+        &Int64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | Int64")] b: Either<
+        &Int64,
+        Unknown,
+    >,
+) -> Result<BigInt> {
+    napi_static::random(env, a, b).map(|n| BigInt::from(n.v))
+}
@@ -0,0 +1,100 @@
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+use crate::{apply_bulk, Int64};
+
+/// Computes `a[i] + b[i]` (or `a[i] + b` if `b` is a scalar) for every element, wrapping around at the boundary of an 64-bit integer, and writes the results into `output`.
+#[napi(namespace = "bulk")]
+pub fn add(
+    env: Env,
+    #[napi(
+        ts_arg_type = "BigInt64Array | Buffer | Array<number | bigint | string | LongBits | Int64>"
+    )]
+    a: Unknown,
+    #[napi(ts_arg_type = "number | bigint | string | LongBits | Int64 | BigInt64Array | Buffer \
+                          | Array<number | bigint | string | LongBits | Int64>")]
+    b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "BigInt64Array | Buffer | Int64[]")] output: Unknown,
+) -> Result<()> {
+    apply_bulk(&env, a, b, output, i64::wrapping_add)
+}
+
+/// Computes `a[i] - b[i]` (or `a[i] - b` if `b` is a scalar) for every element, wrapping around at the boundary of an 64-bit integer, and writes the results into `output`.
+#[napi(namespace = "bulk")]
+pub fn subtract(
+    env: Env,
+    #[napi(
+        ts_arg_type = "BigInt64Array | Buffer | Array<number | bigint | string | LongBits | Int64>"
+    )]
+    a: Unknown,
+    #[napi(ts_arg_type = "number | bigint | string | LongBits | Int64 | BigInt64Array | Buffer \
+                          | Array<number | bigint | string | LongBits | Int64>")]
+    b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "BigInt64Array | Buffer | Int64[]")] output: Unknown,
+) -> Result<()> {
+    apply_bulk(&env, a, b, output, i64::wrapping_sub)
+}
+
+/// Computes `a[i] * b[i]` (or `a[i] * b` if `b` is a scalar) for every element, wrapping around at the boundary of an 64-bit integer, and writes the results into `output`.
+#[napi(namespace = "bulk")]
+pub fn multiply(
+    env: Env,
+    #[napi(
+        ts_arg_type = "BigInt64Array | Buffer | Array<number | bigint | string | LongBits | Int64>"
+    )]
+    a: Unknown,
+    #[napi(ts_arg_type = "number | bigint | string | LongBits | Int64 | BigInt64Array | Buffer \
+                          | Array<number | bigint | string | LongBits | Int64>")]
+    b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "BigInt64Array | Buffer | Int64[]")] output: Unknown,
+) -> Result<()> {
+    apply_bulk(&env, a, b, output, i64::wrapping_mul)
+}
+
+/// Computes `a[i] & b[i]` (or `a[i] & b` if `b` is a scalar) for every element, and writes the results into `output`.
+#[napi(namespace = "bulk")]
+pub fn and(
+    env: Env,
+    #[napi(
+        ts_arg_type = "BigInt64Array | Buffer | Array<number | bigint | string | LongBits | Int64>"
+    )]
+    a: Unknown,
+    #[napi(ts_arg_type = "number | bigint | string | LongBits | Int64 | BigInt64Array | Buffer \
+                          | Array<number | bigint | string | LongBits | Int64>")]
+    b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "BigInt64Array | Buffer | Int64[]")] output: Unknown,
+) -> Result<()> {
+    apply_bulk(&env, a, b, output, |a, b| a & b)
+}
+
+/// Computes `a[i] | b[i]` (or `a[i] | b` if `b` is a scalar) for every element, and writes the results into `output`.
+#[napi(namespace = "bulk")]
+pub fn or(
+    env: Env,
+    #[napi(
+        ts_arg_type = "BigInt64Array | Buffer | Array<number | bigint | string | LongBits | Int64>"
+    )]
+    a: Unknown,
+    #[napi(ts_arg_type = "number | bigint | string | LongBits | Int64 | BigInt64Array | Buffer \
+                          | Array<number | bigint | string | LongBits | Int64>")]
+    b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "BigInt64Array | Buffer | Int64[]")] output: Unknown,
+) -> Result<()> {
+    apply_bulk(&env, a, b, output, |a, b| a | b)
+}
+
+/// Computes `a[i] ^ b[i]` (or `a[i] ^ b` if `b` is a scalar) for every element, and writes the results into `output`.
+#[napi(namespace = "bulk")]
+pub fn xor(
+    env: Env,
+    #[napi(
+        ts_arg_type = "BigInt64Array | Buffer | Array<number | bigint | string | LongBits | Int64>"
+    )]
+    a: Unknown,
+    #[napi(ts_arg_type = "number | bigint | string | LongBits | Int64 | BigInt64Array | Buffer \
+                          | Array<number | bigint | string | LongBits | Int64>")]
+    b: Either<&Int64, Unknown>,
+    #[napi(ts_arg_type = "BigInt64Array | Buffer | Int64[]")] output: Unknown,
+) -> Result<()> {
+    apply_bulk(&env, a, b, output, |a, b| a ^ b)
+}
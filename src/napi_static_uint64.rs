@@ -0,0 +1,626 @@
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+use crate::{throw_divide_by_zero, to_u64, ErrorCode, Ordering, ParseOptions, RawInput, UInt64};
+
+/// Parses a string as a 64-bit unsigned integer.
+///
+/// @param options A radix between 2 and 36, or the parsing options. If the radix is not set, it is decided by the `0b`, `0o` or `0x` prefix of the string, or is 10 without any prefix.
+#[napi(namespace = "uint64")]
+pub fn parse(
+    env: Env,
+    value: String,
+    #[napi(ts_arg_type = "number | ParseOptions")] options: Option<Either<u32, ParseOptions>>,
+) -> Result<UInt64> {
+    UInt64::parse(env, value, options)
+}
+
+/// Formats a 64-bit unsigned integer as a string, like `Number.prototype.toString`.
+///
+/// @param radix An integer between 2 and 36. Default: `10`.
+#[napi(namespace = "uint64")]
+pub fn format(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+        &UInt64,
+        Unknown,
+    >,
+    radix: Option<u32>,
+) -> Result<String> {
+    let a = to_u64(&env, a)?;
+
+    UInt64 {
+        v: a
+    }
+    .to_string(env, radix)
+}
+
+/// Computes `a + b`, wrapping around at the boundary of an 64-bit unsigned integer.
+#[napi(namespace = "uint64")]
+pub fn add(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+        &UInt64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] b: Either<
+        &UInt64,
+        Unknown,
+    >,
+) -> Result<UInt64> {
+    let a = to_u64(&env, a)?;
+    let b = to_u64(&env, b)?;
+
+    Ok(UInt64 {
+        v: a.wrapping_add(b)
+    })
+}
+
+/// Computes `a - b`, wrapping around at the boundary of an 64-bit unsigned integer.
+#[napi(namespace = "uint64")]
+pub fn subtract(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+        &UInt64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] b: Either<
+        &UInt64,
+        Unknown,
+    >,
+) -> Result<UInt64> {
+    let a = to_u64(&env, a)?;
+    let b = to_u64(&env, b)?;
+
+    Ok(UInt64 {
+        v: a.wrapping_sub(b)
+    })
+}
+
+/// Computes `a * b`, wrapping around at the boundary of an 64-bit unsigned integer.
+#[napi(namespace = "uint64")]
+pub fn multiply(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+        &UInt64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] b: Either<
+        &UInt64,
+        Unknown,
+    >,
+) -> Result<UInt64> {
+    let a = to_u64(&env, a)?;
+    let b = to_u64(&env, b)?;
+
+    Ok(UInt64 {
+        v: a.wrapping_mul(b)
+    })
+}
+
+/// Computes `a / b`, wrapping around at the boundary of an 64-bit unsigned integer.
+#[napi(namespace = "uint64")]
+pub fn divide(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+        &UInt64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] b: Either<
+        &UInt64,
+        Unknown,
+    >,
+) -> Result<UInt64> {
+    let a = to_u64(&env, a)?;
+    let input = b.raw_input();
+    let b = to_u64(&env, b)?;
+
+    if b == 0 {
+        return throw_divide_by_zero(
+            &env,
+            format!("attempt to divide {a} by zero"),
+            input,
+            UInt64 {
+                v: b
+            },
+        );
+    }
+
+    Ok(UInt64 {
+        v: a.wrapping_div(b)
+    })
+}
+
+/// Computes `a % b`, wrapping around at the boundary of an 64-bit integer.
+#[napi(namespace = "uint64", js_name = "mod")]
+pub fn modulo(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+        &UInt64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] b: Either<
+        &UInt64,
+        Unknown,
+    >,
+) -> Result<UInt64> {
+    let a = to_u64(&env, a)?;
+    let input = b.raw_input();
+    let b = to_u64(&env, b)?;
+
+    if b == 0 {
+        return throw_divide_by_zero(
+            &env,
+            format!("attempt to calculate the remainder of {a} with a divisor of zero"),
+            input,
+            UInt64 {
+                v: b
+            },
+        );
+    }
+
+    Ok(UInt64 {
+        v: a.wrapping_rem(b)
+    })
+}
+
+/// Computes `a ^ b`, wrapping around at the boundary of an 64-bit unsigned integer.
+#[napi(namespace = "uint64")]
+pub fn pow(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+        &UInt64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] b: Either<
+        &UInt64,
+        Unknown,
+    >,
+) -> Result<UInt64> {
+    let a = to_u64(&env, a)?;
+    let b = to_u64(&env, b)?;
+
+    if b > u32::MAX as u64 {
+        env.throw_range_error(
+            &format!("the exponent of an integer number must not be bigger than {}", u32::MAX),
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    }
+
+    Ok(UInt64 {
+        v: a.wrapping_pow(b as u32)
+    })
+}
+
+/// Computes `a << b`, wrapping around at the boundary of an 64-bit unsigned integer.
+#[napi(namespace = "uint64", js_name = "shiftLeft")]
+pub fn shift_left(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+        &UInt64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] b: Either<
+        &UInt64,
+        Unknown,
+    >,
+) -> Result<UInt64> {
+    let a = to_u64(&env, a)?;
+    let b = to_u64(&env, b)?;
+
+    if b > u32::MAX as u64 {
+        env.throw_range_error(
+            &format!("the bit count for shift must not be bigger than {}", u32::MAX),
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    }
+
+    Ok(UInt64 {
+        v: a.wrapping_shl(b as u32)
+    })
+}
+
+/// Computes `a >> b`, wrapping around at the boundary of an 64-bit unsigned integer.
+#[napi(namespace = "uint64", js_name = "shiftRight")]
+pub fn shift_right(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+        &UInt64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] b: Either<
+        &UInt64,
+        Unknown,
+    >,
+) -> Result<UInt64> {
+    let a = to_u64(&env, a)?;
+    let b = to_u64(&env, b)?;
+
+    if b > u32::MAX as u64 {
+        env.throw_range_error(
+            &format!("the bit count for shift must not be bigger than {}", u32::MAX),
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    }
+
+    Ok(UInt64 {
+        v: a.wrapping_shr(b as u32)
+    })
+}
+
+/// Shifts the bits to the left by a specified amount n, wrapping the truncated bits to the beginning of the resulting 64-bit unsigned integer.
+#[napi(namespace = "uint64", js_name = "rotateLeft")]
+pub fn rotate_left(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+        &UInt64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] b: Either<
+        &UInt64,
+        Unknown,
+    >,
+) -> Result<UInt64> {
+    let a = to_u64(&env, a)?;
+    let b = to_u64(&env, b)?;
+
+    if b > u32::MAX as u64 {
+        env.throw_range_error(
+            &format!("the bit count for rotation must not be bigger than {}", u32::MAX),
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    }
+
+    Ok(UInt64 {
+        v: a.rotate_left(b as u32)
+    })
+}
+
+/// Shifts the bits to the right by a specified amount n, wrapping the truncated bits to the beginning of the resulting 64-bit unsigned integer.
+#[napi(namespace = "uint64", js_name = "rotateRight")]
+pub fn rotate_right(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+        &UInt64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] b: Either<
+        &UInt64,
+        Unknown,
+    >,
+) -> Result<UInt64> {
+    let a = to_u64(&env, a)?;
+    let b = to_u64(&env, b)?;
+
+    if b > u32::MAX as u64 {
+        env.throw_range_error(
+            &format!("the bit count for rotation must not be bigger than {}", u32::MAX),
+            Some(ErrorCode::OutOfRange.as_str()),
+        )?;
+
+        return Err(Error::from_reason(""));
+    }
+
+    Ok(UInt64 {
+        v: a.rotate_right(b as u32)
+    })
+}
+
+/// Computes `a & b`.
+#[napi(namespace = "uint64")]
+pub fn and(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+        &UInt64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] b: Either<
+        &UInt64,
+        Unknown,
+    >,
+) -> Result<UInt64> {
+    let a = to_u64(&env, a)?;
+    let b = to_u64(&env, b)?;
+
+    Ok(UInt64 {
+        v: a & b
+    })
+}
+
+/// Computes `a | b`.
+#[napi(namespace = "uint64")]
+pub fn or(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+        &UInt64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] b: Either<
+        &UInt64,
+        Unknown,
+    >,
+) -> Result<UInt64> {
+    let a = to_u64(&env, a)?;
+    let b = to_u64(&env, b)?;
+
+    Ok(UInt64 {
+        v: a | b
+    })
+}
+
+/// Computes `a ^ b`.
+#[napi(namespace = "uint64")]
+pub fn xor(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+        &UInt64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] b: Either<
+        &UInt64,
+        Unknown,
+    >,
+) -> Result<UInt64> {
+    let a = to_u64(&env, a)?;
+    let b = to_u64(&env, b)?;
+
+    Ok(UInt64 {
+        v: a ^ b
+    })
+}
+
+/// Computes `~(a & b)`.
+#[napi(namespace = "uint64")]
+pub fn nand(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+        &UInt64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] b: Either<
+        &UInt64,
+        Unknown,
+    >,
+) -> Result<UInt64> {
+    let mut n = and(env, a, b)?;
+
+    n.v = !n.v;
+
+    Ok(n)
+}
+
+/// Computes `~(a | b)`.
+#[napi(namespace = "uint64")]
+pub fn nor(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+        &UInt64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] b: Either<
+        &UInt64,
+        Unknown,
+    >,
+) -> Result<UInt64> {
+    let mut n = or(env, a, b)?;
+
+    n.v = !n.v;
+
+    Ok(n)
+}
+
+/// Computes `~(a ^ b)`.
+#[napi(namespace = "uint64")]
+pub fn xnor(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+        &UInt64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] b: Either<
+        &UInt64,
+        Unknown,
+    >,
+) -> Result<UInt64> {
+    let mut n = xor(env, a, b)?;
+
+    n.v = !n.v;
+
+    Ok(n)
+}
+
+/// Computes `~a`.
+#[napi(namespace = "uint64")]
+pub fn not(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+        &UInt64,
+        Unknown,
+    >,
+) -> Result<UInt64> {
+    let a = to_u64(&env, a)?;
+
+    Ok(UInt64 {
+        v: !a
+    })
+}
+
+/// Computes `-a`, wrapping around at the boundary of an 64-bit unsigned integer.
+#[napi(namespace = "uint64")]
+pub fn negative(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+        &UInt64,
+        Unknown,
+    >,
+) -> Result<UInt64> {
+    let a = to_u64(&env, a)?;
+
+    Ok(UInt64 {
+        v: a.wrapping_neg()
+    })
+}
+
+/// Computes `a === b`.
+#[napi(namespace = "uint64")]
+pub fn eq(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+        &UInt64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] b: Either<
+        &UInt64,
+        Unknown,
+    >,
+) -> Result<bool> {
+    let a = to_u64(&env, a)?;
+    let b = to_u64(&env, b)?;
+
+    Ok(a == b)
+}
+
+/// Computes `a !== b`.
+#[napi(namespace = "uint64")]
+pub fn ne(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+        &UInt64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] b: Either<
+        &UInt64,
+        Unknown,
+    >,
+) -> Result<bool> {
+    let a = to_u64(&env, a)?;
+    let b = to_u64(&env, b)?;
+
+    Ok(a != b)
+}
+
+/// Computes `a > b`.
+#[napi(namespace = "uint64")]
+pub fn gt(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+        &UInt64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] b: Either<
+        &UInt64,
+        Unknown,
+    >,
+) -> Result<bool> {
+    let a = to_u64(&env, a)?;
+    let b = to_u64(&env, b)?;
+
+    Ok(a > b)
+}
+
+/// Computes `a >= b`.
+#[napi(namespace = "uint64")]
+pub fn gte(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+        &UInt64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] b: Either<
+        &UInt64,
+        Unknown,
+    >,
+) -> Result<bool> {
+    let a = to_u64(&env, a)?;
+    let b = to_u64(&env, b)?;
+
+    Ok(a >= b)
+}
+
+/// Computes `a < b`.
+#[napi(namespace = "uint64")]
+pub fn lt(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+        &UInt64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] b: Either<
+        &UInt64,
+        Unknown,
+    >,
+) -> Result<bool> {
+    let a = to_u64(&env, a)?;
+    let b = to_u64(&env, b)?;
+
+    Ok(a < b)
+}
+
+/// Computes `a <= b`.
+#[napi(namespace = "uint64")]
+pub fn lte(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+        &UInt64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] b: Either<
+        &UInt64,
+        Unknown,
+    >,
+) -> Result<bool> {
+    let a = to_u64(&env, a)?;
+    let b = to_u64(&env, b)?;
+
+    Ok(a <= b)
+}
+
+/// If `a < b`, returns `-1`.
+/// If `a === b`, returns `0`.
+/// If `a > b`, returns `1`.
+#[napi(namespace = "uint64")]
+pub fn comp(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+        &UInt64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] b: Either<
+        &UInt64,
+        Unknown,
+    >,
+) -> Result<Ordering> {
+    let a = to_u64(&env, a)?;
+    let b = to_u64(&env, b)?;
+
+    Ok(a.cmp(&b).into())
+}
+
+/// Gets a random 64-bit unsigned integer between `a` and `b`.
+#[napi(namespace = "uint64")]
+pub fn random(
+    env: Env,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+        &UInt64,
+        Unknown,
+    >,
+    #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] b: Either<
+        &UInt64,
+        Unknown,
+    >,
+) -> Result<UInt64> {
+    let a = to_u64(&env, a)?;
+    let b = to_u64(&env, b)?;
+
+    Ok(UInt64 {
+        v: random_number::random!(a, b)
+    })
+}
@@ -0,0 +1,689 @@
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+use crate::{
+    check_buffer_bounds, check_radix, parse_string, throw_divide_by_zero, to_string_radix, to_u64,
+    to_u64_with, Endianness, ErrorCode, InputOptions, Int64, Ordering, ParseOptions, RawInput,
+    UInt128, MAX_SAFE_INTEGER,
+};
+
+#[napi]
+pub struct UInt64 {
+    pub(crate) v: u64,
+}
+
+#[napi]
+impl UInt64 {
+    /// @param value The initial value. Default: `0`.
+    /// @param options The options of converting `value`.
+    #[napi(constructor)]
+    pub fn new(
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")]
+        value: Option<Either<&UInt64, Unknown>>,
+        options: Option<InputOptions>,
+    ) -> Result<Self> {
+        match value {
+            Some(value) => {
+                let v = to_u64_with(&env, value, options)?;
+
+                Ok(UInt64 {
+                    v,
+                })
+            },
+            None => Ok(UInt64 {
+                v: 0
+            }),
+        }
+    }
+
+    /// Parses a string as a 64-bit unsigned integer.
+    ///
+    /// @param options A radix between 2 and 36, or the parsing options. If the radix is not set, it is decided by the `0b`, `0o` or `0x` prefix of the string, or is 10 without any prefix.
+    #[napi(factory)]
+    pub fn parse(
+        env: Env,
+        value: String,
+        #[napi(ts_arg_type = "number | ParseOptions")] options: Option<Either<u32, ParseOptions>>,
+    ) -> Result<Self> {
+        let v = parse_string(&env, value, options)?;
+
+        Ok(UInt64 {
+            v,
+        })
+    }
+
+    /// Reads a 64-bit integer from `buffer` at `offset`.
+    ///
+    /// @param offset The position where to start reading. Default: `0`.
+    /// @param endianness The byte order. Default: `"LE"`.
+    #[napi(factory, js_name = "fromBuffer")]
+    pub fn from_buffer(
+        env: Env,
+        buffer: Buffer,
+        offset: Option<u32>,
+        endianness: Option<Endianness>,
+    ) -> Result<Self> {
+        let offset = check_buffer_bounds(&env, buffer.len(), offset, 8)?;
+
+        let mut bytes = [0u8; 8];
+
+        bytes.copy_from_slice(&buffer[offset..offset + 8]);
+
+        Ok(UInt64 {
+            v: from_bytes!(u64, bytes, endianness)
+        })
+    }
+
+    /// To a decimal number in a string.
+    #[napi(js_name = "toDecimal")]
+    pub fn to_decimal(&self) -> String {
+        format!("{}", self.v)
+    }
+
+    #[allow(clippy::inherent_to_string)]
+    /// To a number in a string, like `Number.prototype.toString`.
+    ///
+    /// @param radix An integer between 2 and 36. Default: `10`.
+    #[napi(js_name = "toString")]
+    pub fn to_string(&self, env: Env, radix: Option<u32>) -> Result<String> {
+        match check_radix(&env, radix)? {
+            Some(radix) if radix != 10 => Ok(to_string_radix(self.v as u128, radix, false)),
+            _ => Ok(self.to_decimal()),
+        }
+    }
+
+    /// To a binary number in a string.
+    #[napi(js_name = "toBinary")]
+    pub fn to_binary(&self, format: Option<bool>) -> String {
+        if format.unwrap_or(false) {
+            format!("{:#066b}", self.v)
+        } else {
+            format!("{:b}", self.v)
+        }
+    }
+
+    /// To a octal number in a string.
+    #[napi(js_name = "toOctal")]
+    pub fn to_octal(&self, format: Option<bool>) -> String {
+        if format.unwrap_or(false) {
+            format!("{:#024o}", self.v)
+        } else {
+            format!("{:o}", self.v)
+        }
+    }
+
+    /// To a hex number in a string.
+    #[napi(js_name = "toHex")]
+    pub fn to_hex(&self, format: Option<bool>, uppercase: Option<bool>) -> String {
+        if uppercase.unwrap_or(false) {
+            if format.unwrap_or(false) {
+                format!("{:#018X}", self.v)
+            } else {
+                format!("{:X}", self.v)
+            }
+        } else if format.unwrap_or(false) {
+            format!("{:#018x}", self.v)
+        } else {
+            format!("{:x}", self.v)
+        }
+    }
+
+    /// To a 64-bit buffer.
+    ///
+    /// @param endianness The byte order. Default: `"LE"`.
+    #[napi(js_name = "toBuffer")]
+    pub fn to_buffer(&self, endianness: Option<Endianness>) -> Buffer {
+        Buffer::from(to_bytes!(self.v, endianness).to_vec())
+    }
+
+    /// Writes this 64-bit integer into `buffer` at `offset` in place, and returns `offset` plus the number of bytes written.
+    ///
+    /// @param offset The position where to start writing. Default: `0`.
+    /// @param endianness The byte order. Default: `"LE"`.
+    #[napi(js_name = "writeToBuffer")]
+    pub fn write_to_buffer(
+        &self,
+        env: Env,
+        mut buffer: Buffer,
+        offset: Option<u32>,
+        endianness: Option<Endianness>,
+    ) -> Result<u32> {
+        let offset = check_buffer_bounds(&env, buffer.len(), offset, 8)?;
+
+        buffer[offset..offset + 8].copy_from_slice(&to_bytes!(self.v, endianness));
+
+        Ok((offset + 8) as u32)
+    }
+
+    /// To a number. If this 64-bit unsigned integer number is bigger than `2^53 - 1`, then throws a RangeError.
+    #[napi(js_name = "toNumber")]
+    pub fn to_number(&self, env: Env) -> Result<i64> {
+        if self.v > MAX_SAFE_INTEGER as u64 {
+            env.throw_range_error(
+                format!("{} is bigger than {MAX_SAFE_INTEGER}", self.v).as_str(),
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            Err(Error::from_reason(""))
+        } else {
+            Ok(self.v as i64)
+        }
+    }
+
+    /// To a bigint.
+    #[napi(js_name = "toBigInt")]
+    pub fn to_bigint(&self) -> BigInt {
+        BigInt::from(self.v)
+    }
+
+    /// Sets the value of this instance.
+    #[napi]
+    pub fn set(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")]
+        value: Either<&UInt64, Unknown>,
+    ) -> Result<This> {
+        let v = to_u64(&env, value)?;
+
+        self.v = v;
+
+        Ok(this)
+    }
+
+    /// Computes `self += value`, wrapping around at the boundary of an 64-bit unsigned integer.
+    #[napi]
+    pub fn add(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")]
+        value: Either<&UInt64, Unknown>,
+    ) -> Result<This> {
+        let a = self.v;
+        let b = to_u64(&env, value)?;
+
+        self.v = a.wrapping_add(b);
+
+        Ok(this)
+    }
+
+    /// Computes `self -= value`, wrapping around at the boundary of an 64-bit unsigned integer.
+    #[napi]
+    pub fn subtract(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")]
+        value: Either<&UInt64, Unknown>,
+    ) -> Result<This> {
+        let a = self.v;
+        let b = to_u64(&env, value)?;
+
+        self.v = a.wrapping_sub(b);
+
+        Ok(this)
+    }
+
+    /// Computes `self *= value`, wrapping around at the boundary of an 64-bit unsigned integer.
+    #[napi]
+    pub fn multiply(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")]
+        value: Either<&UInt64, Unknown>,
+    ) -> Result<This> {
+        let a = self.v;
+        let b = to_u64(&env, value)?;
+
+        self.v = a.wrapping_mul(b);
+
+        Ok(this)
+    }
+
+    /// Computes `self /= value`, wrapping around at the boundary of an 64-bit unsigned integer.
+    #[napi]
+    pub fn divide(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")]
+        value: Either<&UInt64, Unknown>,
+    ) -> Result<This> {
+        let a = self.v;
+        let input = value.raw_input();
+        let b = to_u64(&env, value)?;
+
+        if b == 0 {
+            return throw_divide_by_zero(
+                &env,
+                format!("attempt to divide {a} by zero"),
+                input,
+                UInt64 {
+                    v: b
+                },
+            );
+        }
+
+        self.v = a.wrapping_div(b);
+
+        Ok(this)
+    }
+
+    /// Computes `self %= value`, wrapping around at the boundary of an 64-bit unsigned integer.
+    #[napi(js_name = "mod")]
+    pub fn modulo(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")]
+        value: Either<&UInt64, Unknown>,
+    ) -> Result<This> {
+        let a = self.v;
+        let input = value.raw_input();
+        let b = to_u64(&env, value)?;
+
+        if b == 0 {
+            return throw_divide_by_zero(
+                &env,
+                format!("attempt to calculate the remainder of {a} with a divisor of zero"),
+                input,
+                UInt64 {
+                    v: b
+                },
+            );
+        }
+
+        self.v = a.wrapping_rem(b);
+
+        Ok(this)
+    }
+
+    /// Computes `self **= value`, wrapping around at the boundary of an 64-bit unsigned integer.
+    #[napi]
+    pub fn pow(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")]
+        value: Either<&UInt64, Unknown>,
+    ) -> Result<This> {
+        let a = self.v;
+        let b = to_u64(&env, value)?;
+
+        if b > u32::MAX as u64 {
+            env.throw_range_error(
+                &format!("the exponent of an integer number must not be bigger than {}", u32::MAX),
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            return Err(Error::from_reason(""));
+        }
+
+        self.v = a.wrapping_pow(b as u32);
+
+        Ok(this)
+    }
+
+    /// Computes `self <<= value`, wrapping around at the boundary of an 64-bit unsigned integer.
+    #[napi(js_name = "shiftLeft")]
+    pub fn shift_left(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")]
+        value: Either<&UInt64, Unknown>,
+    ) -> Result<This> {
+        let a = self.v;
+        let b = to_u64(&env, value)?;
+
+        if b > u32::MAX as u64 {
+            env.throw_range_error(
+                &format!("the bit count for shift must not be bigger than {}", u32::MAX),
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            return Err(Error::from_reason(""));
+        }
+
+        self.v = a.wrapping_shl(b as u32);
+
+        Ok(this)
+    }
+
+    /// Computes `self >>= value`, wrapping around at the boundary of an 64-bit unsigned integer.
+    #[napi(js_name = "shiftRight")]
+    pub fn shift_right(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")]
+        value: Either<&UInt64, Unknown>,
+    ) -> Result<This> {
+        let a = self.v;
+        let b = to_u64(&env, value)?;
+
+        if b > u32::MAX as u64 {
+            env.throw_range_error(
+                &format!("the bit count for shift must not be bigger than {}", u32::MAX),
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            return Err(Error::from_reason(""));
+        }
+
+        self.v = a.wrapping_shr(b as u32);
+
+        Ok(this)
+    }
+
+    /// Shifts the bits to the left by a specified amount n, wrapping the truncated bits to the beginning of the resulting 64-bit unsigned integer.
+    #[napi(js_name = "rotateLeft")]
+    pub fn rotate_left(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")]
+        value: Either<&UInt64, Unknown>,
+    ) -> Result<This> {
+        let a = self.v;
+        let b = to_u64(&env, value)?;
+
+        if b > u32::MAX as u64 {
+            env.throw_range_error(
+                &format!("the bit count for rotation must not be bigger than {}", u32::MAX),
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            return Err(Error::from_reason(""));
+        }
+
+        self.v = a.rotate_left(b as u32);
+
+        Ok(this)
+    }
+
+    /// Shifts the bits to the right by a specified amount n, wrapping the truncated bits to the beginning of the resulting 64-bit unsigned integer.
+    #[napi(js_name = "rotateRight")]
+    pub fn rotate_right(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")]
+        value: Either<&UInt64, Unknown>,
+    ) -> Result<This> {
+        let a = self.v;
+        let b = to_u64(&env, value)?;
+
+        if b > u32::MAX as u64 {
+            env.throw_range_error(
+                &format!("the bit count for rotation must not be bigger than {}", u32::MAX),
+                Some(ErrorCode::OutOfRange.as_str()),
+            )?;
+
+            return Err(Error::from_reason(""));
+        }
+
+        self.v = a.rotate_right(b as u32);
+
+        Ok(this)
+    }
+
+    /// Computes `self &= value`.
+    #[napi]
+    pub fn and(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")]
+        value: Either<&UInt64, Unknown>,
+    ) -> Result<This> {
+        self.v &= to_u64(&env, value)?;
+
+        Ok(this)
+    }
+
+    /// Computes `self |= value`.
+    #[napi]
+    pub fn or(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")]
+        value: Either<&UInt64, Unknown>,
+    ) -> Result<This> {
+        self.v |= to_u64(&env, value)?;
+
+        Ok(this)
+    }
+
+    /// Computes `self ^= value`.
+    #[napi]
+    pub fn xor(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")]
+        value: Either<&UInt64, Unknown>,
+    ) -> Result<This> {
+        self.v ^= to_u64(&env, value)?;
+
+        Ok(this)
+    }
+
+    /// Computes `self = ~(self & value)`.
+    #[napi]
+    pub fn nand(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")]
+        value: Either<&UInt64, Unknown>,
+    ) -> Result<This> {
+        self.v = !(self.v & to_u64(&env, value)?);
+
+        Ok(this)
+    }
+
+    /// Computes `self = ~(self | value)`.
+    #[napi]
+    pub fn nor(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")]
+        value: Either<&UInt64, Unknown>,
+    ) -> Result<This> {
+        self.v = !(self.v | to_u64(&env, value)?);
+
+        Ok(this)
+    }
+
+    /// Computes `self = ~(self ^ value)`.
+    #[napi]
+    pub fn xnor(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")]
+        value: Either<&UInt64, Unknown>,
+    ) -> Result<This> {
+        self.v = !(self.v ^ to_u64(&env, value)?);
+
+        Ok(this)
+    }
+
+    /// Computes `self = ~self`.
+    #[napi]
+    pub fn not(&mut self, this: This) -> Result<This> {
+        self.v = !self.v;
+
+        Ok(this)
+    }
+
+    /// Computes `self = -self`, wrapping around at the boundary of an 64-bit unsigned integer.
+    #[napi]
+    pub fn negative(&mut self, this: This) -> Result<This> {
+        self.v = self.v.wrapping_neg();
+
+        Ok(this)
+    }
+
+    /// Computes `self === value`.
+    #[napi]
+    pub fn eq(
+        &self,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")]
+        value: Either<&UInt64, Unknown>,
+    ) -> Result<bool> {
+        let a = self.v;
+        let b = to_u64(&env, value)?;
+
+        Ok(a == b)
+    }
+
+    /// Computes `self !== value`.
+    #[napi]
+    pub fn ne(
+        &self,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")]
+        value: Either<&UInt64, Unknown>,
+    ) -> Result<bool> {
+        let a = self.v;
+        let b = to_u64(&env, value)?;
+
+        Ok(a != b)
+    }
+
+    /// Computes `self > value`.
+    #[napi]
+    pub fn gt(
+        &self,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")]
+        value: Either<&UInt64, Unknown>,
+    ) -> Result<bool> {
+        let a = self.v;
+        let b = to_u64(&env, value)?;
+
+        Ok(a > b)
+    }
+
+    /// Computes `self >= value`.
+    #[napi]
+    pub fn gte(
+        &self,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")]
+        value: Either<&UInt64, Unknown>,
+    ) -> Result<bool> {
+        let a = self.v;
+        let b = to_u64(&env, value)?;
+
+        Ok(a >= b)
+    }
+
+    /// Computes `self < value`.
+    #[napi]
+    pub fn lt(
+        &self,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")]
+        value: Either<&UInt64, Unknown>,
+    ) -> Result<bool> {
+        let a = self.v;
+        let b = to_u64(&env, value)?;
+
+        Ok(a < b)
+    }
+
+    /// Computes `self <= value`.
+    #[napi]
+    pub fn lte(
+        &self,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")]
+        value: Either<&UInt64, Unknown>,
+    ) -> Result<bool> {
+        let a = self.v;
+        let b = to_u64(&env, value)?;
+
+        Ok(a <= b)
+    }
+
+    /// If `self < value`, returns `-1`.
+    /// If `self === value`, returns `0`.
+    /// If `self > value`, returns `1`.
+    #[napi]
+    pub fn comp(
+        &self,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")]
+        value: Either<&UInt64, Unknown>,
+    ) -> Result<Ordering> {
+        let a = self.v;
+        let b = to_u64(&env, value)?;
+
+        Ok(a.cmp(&b).into())
+    }
+
+    /// Set a random 64-bit unsigned integer between `self` and `value`.
+    #[napi]
+    pub fn random(
+        &mut self,
+        this: This,
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")]
+        value: Either<&UInt64, Unknown>,
+    ) -> Result<This> {
+        let a = self.v;
+        let b = to_u64(&env, value)?;
+
+        self.v = random_number::random!(a, b);
+
+        Ok(this)
+    }
+
+    /// Computes `a * b` without overflow, and returns the exact product as a 128-bit unsigned integer.
+    #[napi(js_name = "widenMultiply")]
+    pub fn widen_multiply(
+        env: Env,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] a: Either<
+            &UInt64,
+            Unknown,
+        >,
+        #[napi(ts_arg_type = "number | bigint | string | Buffer | LongBits | UInt64")] b: Either<
+            &UInt64,
+            Unknown,
+        >,
+    ) -> Result<UInt128> {
+        let a = to_u64(&env, a)?;
+        let b = to_u64(&env, b)?;
+
+        Ok(UInt128 {
+            v: a as u128 * b as u128
+        })
+    }
+
+    /// Reinterprets the bits of this 64-bit unsigned integer as a 64-bit signed integer.
+    #[napi(js_name = "toSigned")]
+    pub fn to_signed(&self) -> Int64 {
+        Int64 {
+            v: self.v as i64
+        }
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    /// Clones this `UInt64` object.
+    #[napi]
+    pub fn clone(&self) -> UInt64 {
+        UInt64 {
+            v: self.v
+        }
+    }
+}
@@ -1,7 +1,17 @@
 import {
-    Int64, add, gt, subtract,
+    Decimal64, DecimalRounding, DivisionRounding, Endianness, ErrorCode, Fixed64, Int128, Int64, NumberRounding, OverflowMode, ShiftMode, UInt128, UInt64, absDiff, add, bigint, bitLength, bulk, checkedAdd, checkedMultiply, checkedNeg, countOnes, divCeil, divEuclid, divFloor, divMod, divide, factorize, format, fromBigEndian, gcd, gt, ilog, ilog10, isPowerOfTwo, isPrime, isqrt, lcm, leadingZeros, midpoint, modFloor, modInverse, mulMod, nextPowerOfTwo, nextPrime, overflowingAdd, parse, powMod, prevPrime, remEuclid, reverseBits, saturatingAdd, saturatingNeg, setStrictInput, shiftLeft, shiftRight, shiftRightUnsigned, subtract, swapBytes, toBigEndian, uint64, withStrictInput,
 } from "../src/lib.js";
 
+const catchError = (f: () => unknown): unknown => {
+    try {
+        f();
+    } catch (error) {
+        return error;
+    }
+
+    return undefined;
+};
+
 describe("Add", () => {
     it("should add", () => {
         let n: number | Int64 = 0;
@@ -69,6 +79,13 @@ describe("Divide", () => {
         n.divide(2);
 
         expect(n.toDecimal()).toBe("6082255020441600");
+
+        expect(divide("-9223372036854775808", -1).toDecimal()).toBe("-9223372036854775808");
+    });
+
+    it("should throw on division by zero", () => {
+        expect(catchError(() => divide(1, 0))).toMatchObject({ code: "INT64_DIVIDE_BY_ZERO" });
+        expect(() => new Int64(1).mod(0)).toThrow(RangeError);
     });
 });
 
@@ -86,6 +103,86 @@ describe("Mod", () => {
     });
 });
 
+describe("Division Modes", () => {
+    it("should round the quotient", () => {
+        expect(divEuclid(-7, 3).toNumber()).toBe(-3);
+        expect(remEuclid(-7, 3).toNumber()).toBe(2);
+        expect(remEuclid(-7, -3).toNumber()).toBe(2);
+        expect(divFloor(7, -3).toNumber()).toBe(-3);
+        expect(modFloor(7, -3).toNumber()).toBe(-2);
+        expect(divCeil(7, 3).toNumber()).toBe(3);
+        expect(new Int64(-7).divFloor(2).toNumber()).toBe(-4);
+
+        const { quotient, remainder } = divMod(-7, 2, DivisionRounding.Floor);
+
+        expect(quotient.toNumber()).toBe(-4);
+        expect(remainder.toNumber()).toBe(1);
+        expect(new Int64(-7).divMod(2).remainder.toNumber()).toBe(-1);
+
+        expect(catchError(() => remEuclid(1, 0))).toMatchObject({ code: ErrorCode.DivideByZero, input: 0 });
+    });
+});
+
+describe("Integer Math", () => {
+    it("should compute integer functions", () => {
+        expect(new Int64(-7).abs().toNumber()).toBe(7);
+        expect(new Int64("-9223372036854775808").unsignedAbs().toDecimal()).toBe("9223372036854775808");
+        expect(absDiff("-9223372036854775808", "9223372036854775807").toDecimal()).toBe("18446744073709551615");
+        expect(new Int64(-7).signum().toNumber()).toBe(-1);
+        expect(new Int64(0).isZero()).toBe(true);
+        expect(gcd(-12, 18).toNumber()).toBe(6);
+        expect(lcm(-4, 6).toNumber()).toBe(12);
+        expect(isqrt("9223372036854775807").toNumber()).toBe(3037000499);
+        expect(ilog10(1000)).toBe(3);
+        expect(ilog(80, 3)).toBe(3);
+        expect(new Int64(1024).ilog2()).toBe(10);
+        expect(midpoint("9223372036854775807", "9223372036854775805").toDecimal()).toBe("9223372036854775806");
+        expect(midpoint(-3, 0).toNumber()).toBe(-1);
+
+        expect(catchError(() => gcd("-9223372036854775808", 0))).toMatchObject({ code: ErrorCode.Overflow });
+        expect(catchError(() => isqrt(-1))).toMatchObject({ code: ErrorCode.OutOfRange });
+        expect(catchError(() => ilog(8, 1))).toMatchObject({ code: ErrorCode.OutOfRange });
+    });
+});
+
+describe("Modular Arithmetic", () => {
+    it("should compute without overflow", () => {
+        expect(mulMod("9223372036854775807", "9223372036854775807", "9223372036854775783").toNumber()).toBe(576);
+        expect(new Int64("9223372036854775807").addMod("9223372036854775807", 10).toNumber()).toBe(4);
+        expect(mulMod(-3, 5, 7).toNumber()).toBe(6);
+        expect(powMod(3, 200, 1000000007).toNumber()).toBe(136318165);
+        expect(modInverse(3, 11).toNumber()).toBe(4);
+        expect(new Int64(10).modInverse(17).mulMod(10, 17).toNumber()).toBe(1);
+
+        expect(catchError(() => modInverse(4, 8))).toMatchObject({ code: ErrorCode.NotInvertible });
+        expect(catchError(() => mulMod(4, 8, 0))).toMatchObject({ code: ErrorCode.DivideByZero, input: 0 });
+    });
+});
+
+describe("Prime Numbers", () => {
+    it("should test primality", () => {
+        expect(isPrime("9223372036854775783")).toBe(true);
+        expect(isPrime("9223372036854775807")).toBe(false);
+        expect(isPrime(3215031751)).toBe(false);
+        expect(isPrime(-7)).toBe(false);
+        expect(nextPrime(100).toNumber()).toBe(101);
+        expect(prevPrime(100).toNumber()).toBe(97);
+        expect(new Int64(13).nextPrime().toNumber()).toBe(17);
+
+        expect(catchError(() => nextPrime("9223372036854775783"))).toMatchObject({ code: ErrorCode.Overflow });
+        expect(catchError(() => prevPrime(2))).toMatchObject({ code: ErrorCode.OutOfRange });
+    });
+
+    it("should factorize", () => {
+        expect(factorize(360).map((n) => n.toNumber())).toEqual([2, 2, 2, 3, 3, 5]);
+        expect(factorize(1)).toEqual([]);
+        expect(factorize("9223371873002223329").map((n) => n.toNumber())).toEqual([3037000453, 3037000493]);
+        expect(new Int64("9223372036854775807").factorize().map((n) => n.toNumber())).toEqual([7, 7, 73, 127, 337, 92737, 649657]);
+
+        expect(catchError(() => factorize(0))).toMatchObject({ code: ErrorCode.OutOfRange });
+    });
+});
+
 describe("Negative", () => {
     it("should negative", () => {
         const n = new Int64("894453210654871");
@@ -97,6 +194,10 @@ describe("Negative", () => {
         n.negative();
 
         expect(n.toDecimal()).toBe("894453210654871");
+
+        n.set("-9223372036854775808").negative();
+
+        expect(n.toDecimal()).toBe("-9223372036854775808");
     });
 });
 
@@ -114,3 +215,472 @@ describe("Gt", () => {
         expect(n).toBe(true);
     });
 });
+
+describe("Shift Modes", () => {
+    it("should treat big bit counts by the mode", () => {
+        expect(shiftLeft(1, 64).toNumber()).toBe(1);
+        expect(shiftLeft(1, 64, ShiftMode.Mask).toNumber()).toBe(1);
+        expect(shiftLeft(1, 64, ShiftMode.Saturate).toNumber()).toBe(0);
+        expect(shiftRight(-8, 100, ShiftMode.Saturate).toNumber()).toBe(-1);
+        expect(shiftRightUnsigned(-8, 64, ShiftMode.Saturate).toNumber()).toBe(0);
+        expect(new Int64(1).shiftLeft(3, ShiftMode.Strict).toNumber()).toBe(8);
+        expect(new Int64(1).rotateLeft(65, ShiftMode.Saturate).toNumber()).toBe(2);
+
+        expect(catchError(() => shiftLeft(1, 64, ShiftMode.Strict))).toMatchObject({ code: ErrorCode.OutOfRange });
+        expect(catchError(() => new Int64(1).rotateRight(64, ShiftMode.Strict))).toMatchObject({ code: ErrorCode.OutOfRange });
+    });
+});
+
+describe("Bit Inspection", () => {
+    it("should count bits", () => {
+        expect(countOnes(-1)).toBe(64);
+        expect(leadingZeros(1)).toBe(63);
+        expect(new Int64(8).trailingZeros()).toBe(3);
+        expect(new Int64(-2).leadingOnes()).toBe(63);
+        expect(new Int64(7).trailingOnes()).toBe(3);
+        expect(new Int64(0).countZeros()).toBe(64);
+        expect(bitLength(255)).toBe(8);
+        expect(bitLength(-256)).toBe(8);
+        expect(isPowerOfTwo(64)).toBe(true);
+        expect(isPowerOfTwo("-9223372036854775808")).toBe(false);
+        expect(nextPowerOfTwo(1000).toNumber()).toBe(1024);
+        expect(new Int64(-3).nextPowerOfTwo().toNumber()).toBe(1);
+
+        expect(catchError(() => nextPowerOfTwo("4611686018427387905"))).toMatchObject({ code: ErrorCode.Overflow });
+    });
+});
+
+describe("Bit Manipulation", () => {
+    it("should manipulate bits and bit fields", () => {
+        const n = new Int64(0).setBit(63).setBit(3);
+
+        expect(n.toDecimal()).toBe("-9223372036854775800");
+        expect(n.getBit(3)).toBe(true);
+        expect(n.clearBit(63).toggleBit(0).toNumber()).toBe(9);
+
+        expect(new Int64("0x0000000000ABCDEF").extractBits(8, 8).toNumber()).toBe(0xCD);
+        expect(new Int64(0).insertBits(8, 4, 0b1010).extractBits(8, 4, true).toNumber()).toBe(-6);
+        expect(new Int64(-1).insertBits(4, 4, 0).toHex()).toBe("ffffffffffffff0f");
+
+        expect(catchError(() => n.getBit(64))).toMatchObject({ code: ErrorCode.OutOfRange });
+        expect(catchError(() => n.extractBits(60, 5))).toMatchObject({ code: ErrorCode.OutOfRange });
+    });
+});
+
+describe("Byte Order", () => {
+    it("should reorder bytes and bits", () => {
+        expect(swapBytes("0x0123456789ABCDEF").toHex(true)).toBe("0xefcdab8967452301");
+        expect(reverseBits(1).toHex(true)).toBe("0x8000000000000000");
+        expect(new Int64("0x0123456789ABCDEF").swapBytes().swapBytes().toHex()).toBe("123456789abcdef");
+        expect(fromBigEndian(toBigEndian(42)).toNumber()).toBe(42);
+        expect(new Int64(42).toLittleEndian().fromLittleEndian().toNumber()).toBe(42);
+    });
+});
+
+describe("BigInt", () => {
+    it("should accept and return bigints", () => {
+        const n = new Int64(9007199254740991n);
+
+        n.add(2n);
+
+        expect(n.toBigInt()).toBe(9007199254740993n);
+        expect(n.toDecimal()).toBe("9007199254740993");
+
+        expect(bigint.add(-9223372036854775808n, 0)).toBe(-9223372036854775808n);
+        expect(bigint.subtract(1, 2n)).toBe(-1n);
+        expect(bigint.gcd(12n, 18)).toBe(6n);
+        expect(bigint.saturatingAdd("9223372036854775807", 1)).toBe(9223372036854775807n);
+        expect(bigint.unsignedAbs(-5)).toBe(5n);
+        expect(bigint.factorize(60)).toEqual([2n, 2n, 3n, 5n]);
+
+        expect(() => add(9223372036854775808n, 0)).toThrow(RangeError);
+    });
+});
+
+describe("Bulk", () => {
+    it("should compute element-wise", () => {
+        const a = new BigInt64Array([1n, 2n, 9223372036854775807n]);
+        const output = new BigInt64Array(3);
+
+        bulk.add(a, 1, output);
+        expect(output).toEqual(new BigInt64Array([2n, 3n, -9223372036854775808n]));
+
+        bulk.multiply(a, new BigInt64Array([3n, 4n, 2n]), a);
+        expect(a).toEqual(new BigInt64Array([3n, 8n, -2n]));
+
+        const buffer = Buffer.alloc(16);
+
+        buffer.writeBigInt64LE(-6n, 8);
+        bulk.or(buffer, [5, 1], buffer);
+        expect(buffer.readBigInt64LE(0)).toBe(5n);
+        expect(buffer.readBigInt64LE(8)).toBe(-5n);
+
+        const first = new Int64(0);
+        const results: Int64[] = [first, new Int64(0)];
+
+        bulk.xor([new Int64(10), "3"], new Int64(1), results);
+        expect(results.map((n) => n.toNumber())).toEqual([11, 2]);
+        expect(results[0]).toBe(first);
+    });
+
+    it("should throw errors", () => {
+        expect(catchError(() => bulk.add([1, 2], [1], []))).toMatchObject({ code: ErrorCode.BadBufferLength });
+        expect(catchError(() => bulk.add(Buffer.alloc(7), 1, []))).toMatchObject({ code: ErrorCode.BadBufferLength });
+        expect(catchError(() => bulk.and([1], 1, new BigInt64Array(2)))).toMatchObject({ code: ErrorCode.BadBufferLength });
+        expect(catchError(() => bulk.or([1, 2], 1, [new Int64(0)]))).toMatchObject({ code: ErrorCode.BadBufferLength });
+        expect(catchError(() => bulk.subtract(new Int32Array(2), 1, []))).toMatchObject({ code: ErrorCode.UnsupportedType });
+    });
+});
+
+describe("Long.js", () => {
+    it("should be compatible with Long.js", () => {
+        const n = Int64.fromBits(-1, 0x7FFFFFFF);
+
+        expect(n.toDecimal()).toBe("9223372036854775807");
+        expect(n.getHighBits()).toBe(0x7FFFFFFF);
+        expect(n.getLowBits()).toBe(-1);
+        expect(n.getHighBitsUnsigned()).toBe(0x7FFFFFFF);
+        expect(n.getLowBitsUnsigned()).toBe(0xFFFFFFFF);
+        expect(Int64.fromBits(0, -0x80000000).toDecimal()).toBe("-9223372036854775808");
+
+        expect(add({ low: 1, high: 1 }, 1).toNumber()).toBe(4294967298);
+        expect(new Int64({ low: -1, high: -1 }).toNumber()).toBe(-1);
+        expect(new UInt64({ low: -1, high: -1, unsigned: true }).toDecimal()).toBe("18446744073709551615");
+
+        expect(catchError(() => new Int64({ low: -1, high: -1, unsigned: true }))).toMatchObject({ code: ErrorCode.OutOfRange });
+    });
+});
+
+describe("Number Conversion", () => {
+    it("should convert to numbers explicitly", () => {
+        const n = new Int64("9007199254740993");
+
+        expect(n.isSafeInteger()).toBe(false);
+        expect(n.toNumberLossy()).toBe(9007199254740992);
+        expect(n.toNumberOrNull()).toBe(null);
+        expect(new Int64(-5).toNumberOrNull()).toBe(-5);
+    });
+
+    it("should convert from numbers with rounding", () => {
+        expect(Int64.fromNumber(-1.5).toNumber()).toBe(-1);
+        expect(Int64.fromNumber(-1.5, { rounding: NumberRounding.Floor }).toNumber()).toBe(-2);
+        expect(Int64.fromNumber(-1.5, { rounding: NumberRounding.Ceil }).toNumber()).toBe(-1);
+        expect(Int64.fromNumber(2.5, { rounding: NumberRounding.Round }).toNumber()).toBe(3);
+        expect(Int64.fromNumber(Infinity).toDecimal()).toBe("9223372036854775807");
+        expect(Int64.fromNumber(42, { rounding: NumberRounding.Strict }).toNumber()).toBe(42);
+
+        expect(catchError(() => Int64.fromNumber(1.5, { rounding: NumberRounding.Strict }))).toMatchObject({ code: ErrorCode.OutOfRange, input: 1.5 });
+        expect(catchError(() => Int64.fromNumber(NaN, { rounding: NumberRounding.Strict }))).toMatchObject({ code: ErrorCode.OutOfRange });
+        expect(catchError(() => Int64.fromNumber(2 ** 63, { rounding: NumberRounding.Strict }))).toMatchObject({ code: ErrorCode.OutOfRange });
+    });
+});
+
+describe("Strict Input", () => {
+    it("should reject unsafe numbers", () => {
+        expect(add(2 ** 53 - 1, 1).toDecimal()).toBe("9007199254740992");
+
+        expect(catchError(() => add(1.9, 1))).toMatchObject({ code: ErrorCode.UnsafeNumber, input: 1.9 });
+        expect(catchError(() => new Int64(NaN))).toMatchObject({ code: ErrorCode.UnsafeNumber });
+        expect(catchError(() => new UInt64(2 ** 60 + 1))).toMatchObject({ code: ErrorCode.UnsafeNumber });
+    });
+
+    it("should accept unsafe numbers when the policy is disabled", () => {
+        expect(withStrictInput(false, () => add(1.9, 1).toNumber())).toBe(2);
+        expect(catchError(() => add(1.9, 1))).toMatchObject({ code: ErrorCode.UnsafeNumber });
+
+        setStrictInput(false);
+
+        try {
+            expect(add(2 ** 60, 1).toDecimal()).toBe("1152921504606846977");
+            expect(new Int64(NaN).toNumber()).toBe(0);
+            expect(new Int64(-1.9).toNumber()).toBe(-1);
+            expect(new Int64(Infinity).toDecimal()).toBe("9223372036854775807");
+            expect(new Int64(-1e30).toDecimal()).toBe(Int64.fromNumber(-1e30).toDecimal());
+        } finally {
+            setStrictInput(true);
+        }
+    });
+
+    it("should take the strict option of a single conversion", () => {
+        expect(new Int64(1.9, { strict: false }).toNumber()).toBe(1);
+        expect(catchError(() => new Int64(1.9, {}))).toMatchObject({ code: ErrorCode.UnsafeNumber, input: 1.9 });
+        expect(Decimal64.fromUnscaled(150.5, 2, { strict: false }).toString()).toBe("1.50");
+        expect(Fixed64.fromRaw(2 ** 32 + 0.5, 32, { strict: false }).toNumber()).toBe(1);
+
+        setStrictInput(false);
+
+        try {
+            expect(catchError(() => new UInt64(2.5, { strict: true }))).toMatchObject({ code: ErrorCode.UnsafeNumber, input: 2.5 });
+            expect(catchError(() => new Int128(2 ** 60, { strict: true }))).toMatchObject({ code: ErrorCode.UnsafeNumber });
+            expect(new UInt128(7.7).toNumber()).toBe(7);
+        } finally {
+            setStrictInput(true);
+        }
+    });
+});
+
+describe("UInt64", () => {
+    it("should compute unsigned integers", () => {
+        const n = new UInt64("18446744073709551615");
+
+        expect(n.toBigInt()).toBe(18446744073709551615n);
+        expect(n.toSigned().toDecimal()).toBe("-1");
+
+        n.add(2);
+
+        expect(n.toDecimal()).toBe("1");
+
+        expect(uint64.subtract(0, 1).toHex()).toBe("ffffffffffffffff");
+        expect(uint64.shiftRight("0xFFFFFFFFFFFFFFFF", 60).toNumber()).toBe(15);
+        expect(new Int64(-1).toUnsigned().toDecimal()).toBe("18446744073709551615");
+
+        expect(() => new UInt64(-1)).toThrow(RangeError);
+    });
+});
+
+describe("Int128", () => {
+    it("should compute 128-bit integers", () => {
+        expect(Int64.widenMultiply("9223372036854775807", "-9223372036854775808").toBigInt()).toBe(-85070591730234615856620279821087277056n);
+        expect(UInt64.widenMultiply("18446744073709551615", "18446744073709551615").toDecimal()).toBe("340282366920938463426481119284349108225");
+
+        const n = new Int128(new Int64(-2));
+
+        expect(Int128.fromBuffer(n.toBuffer(Endianness.Big), 0, Endianness.Big).toDecimal()).toBe("-2");
+        expect(n.toUnsigned().toHex()).toBe("fffffffffffffffffffffffffffffffe");
+        expect(n.toInt64().toNumber()).toBe(-2);
+        expect(new UInt128(1).shiftLeft(127).toSigned().toBigInt()).toBe(-(2n ** 127n));
+
+        expect(catchError(() => new Int128(2n ** 127n))).toMatchObject({ code: ErrorCode.OutOfRange });
+        expect(catchError(() => new Int128(2n ** 64n).toInt64())).toMatchObject({ code: ErrorCode.OutOfRange });
+        expect(catchError(() => new Int128(Buffer.alloc(8)))).toMatchObject({ code: ErrorCode.BadBufferLength });
+    });
+});
+
+describe("Decimal64", () => {
+    it("should compute fixed-point decimal numbers", () => {
+        const n = new Decimal64("-1234.5678");
+
+        expect(n.getScale()).toBe(4);
+        expect(n.getUnscaled().toNumber()).toBe(-12345678);
+        expect(n.toFixed(2)).toBe("-1234.57");
+        expect(n.toFixed(6)).toBe("-1234.567800");
+        expect(n.toNumber()).toBe(-1234.5678);
+        expect(Decimal64.parse("9.007199254740993").toNumber()).toBe(9.007199254740993);
+
+        expect(new Decimal64("10.00").add("0.005").toString()).toBe("10.005");
+        expect(new Decimal64("10.00").subtract(Decimal64.fromUnscaled(5, 3)).toString()).toBe("9.995");
+        expect(new Decimal64("19.99").multiply("0.075").toString()).toBe("1.50");
+        expect(new Decimal64("10.00").divide(3).toString()).toBe("3.33");
+        expect(new Decimal64("10.00").divide(3, DecimalRounding.Ceil).toString()).toBe("3.34");
+        expect(new Decimal64("1.5", 3).compare("1.50")).toBe(0);
+    });
+
+    it("should round", () => {
+        expect(new Decimal64("2.5").rescale(0).toString()).toBe("2");
+        expect(new Decimal64("3.5").rescale(0).toString()).toBe("4");
+        expect(new Decimal64("-2.5").rescale(0, DecimalRounding.HalfUp).toString()).toBe("-3");
+        expect(new Decimal64("-2.1").rescale(0, DecimalRounding.Floor).toString()).toBe("-3");
+        expect(new Decimal64("-2.9").rescale(0, DecimalRounding.Trunc).toString()).toBe("-2");
+        expect(new Decimal64("0.125").toFixed(2, DecimalRounding.HalfUp)).toBe("0.13");
+    });
+
+    it("should convert numbers exactly", () => {
+        expect(new Decimal64(0.1).toString()).toBe("0.1");
+        expect(new Decimal64("1.00").add(0.25).toString()).toBe("1.25");
+        expect(new Decimal64("2.00").multiply(1.5).toString()).toBe("3.00");
+        expect(withStrictInput(false, () => new Decimal64(0.1).toString())).toBe("0.1");
+        expect(withStrictInput(false, () => new Decimal64("1.00").add(0.25).toString())).toBe("1.25");
+        expect(withStrictInput(false, () => new Decimal64("2.00").multiply(1.5).toString())).toBe("3.00");
+
+        expect(catchError(() => new Decimal64(1e-30))).toMatchObject({ code: ErrorCode.OutOfRange, input: 1e-30 });
+        expect(catchError(() => new Decimal64(NaN))).toMatchObject({ code: ErrorCode.UnsafeNumber });
+    });
+
+    it("should throw errors", () => {
+        expect(catchError(() => new Decimal64("1.55", 1))).toMatchObject({ code: ErrorCode.OutOfRange });
+        expect(catchError(() => Decimal64.parse("1.2.3"))).toMatchObject({ code: ErrorCode.ParseError, position: 3 });
+        expect(catchError(() => new Decimal64("9223372036854775807").add(1))).toMatchObject({ code: ErrorCode.Overflow });
+        expect(catchError(() => new Decimal64("1.0").divide("0.00"))).toMatchObject({ code: ErrorCode.DivideByZero, input: "0.00" });
+        expect(catchError(() => new Decimal64(1, 19))).toMatchObject({ code: ErrorCode.OutOfRange });
+    });
+});
+
+describe("Fixed64", () => {
+    it("should compute binary fixed-point numbers", () => {
+        const n = new Fixed64(1.5);
+
+        expect(n.getFracBits()).toBe(32);
+        expect(n.getRaw().toHex()).toBe("180000000");
+        expect(n.toBuffer()).toEqual(n.getRaw().toBuffer());
+        expect(Fixed64.fromBuffer(new Fixed64(-0.75).toBuffer("BE"), 0, "BE").toNumber()).toBe(-0.75);
+
+        expect(new Fixed64(2.5).multiply(-1.5).toString()).toBe("-3.75");
+        expect(new Fixed64(1).divide(3).toFixed(10)).toBe("0.3333333333");
+        expect(new Fixed64(0.5).add(new Fixed64(0.25, 8)).toNumber()).toBe(0.75);
+        expect(new Fixed64(2).sqrt().toFixed(9)).toBe("1.414213562");
+        expect(new Fixed64("-3.14159").toFixed(5)).toBe("-3.14159");
+        expect(Fixed64.fromRaw(1, 4).toString()).toBe("0.0625");
+    });
+
+    it("should saturate or wrap", () => {
+        const max = Fixed64.fromRaw("9223372036854775807");
+
+        expect(max.clone().add(1, OverflowMode.Saturate).getRaw().toDecimal()).toBe("9223372036854775807");
+        expect(max.clone().add(1, OverflowMode.Wrap).getRaw().toDecimal()).toBe("-9223372032559808513");
+        expect(new Fixed64(65536).multiply(65536, OverflowMode.Saturate).getRaw().toDecimal()).toBe("9223372036854775807");
+        expect(new Fixed64(65536).multiply(65536).toNumber()).toBe(0);
+    });
+
+    it("should throw errors", () => {
+        expect(catchError(() => new Fixed64(1).divide(0))).toMatchObject({ code: ErrorCode.DivideByZero, input: 0 });
+        expect(catchError(() => new Fixed64(-1).sqrt())).toMatchObject({ code: ErrorCode.OutOfRange });
+        expect(catchError(() => new Fixed64(2 ** 31))).toMatchObject({ code: ErrorCode.OutOfRange, input: 2 ** 31 });
+        expect(catchError(() => new Fixed64(1, 64))).toMatchObject({ code: ErrorCode.OutOfRange });
+    });
+
+    it("should parse its strings back exactly", () => {
+        const values = [
+            Fixed64.fromRaw(1, 32),
+            Fixed64.fromRaw("9223372036854775807", 32),
+            Fixed64.fromRaw("-9223372036854775807", 32),
+            Fixed64.fromRaw(1, 63),
+            Fixed64.fromRaw("-9223372036854775808", 63),
+        ];
+
+        for (const n of values) {
+            expect(Fixed64.parse(n.toString(), n.getFracBits()).getRaw().toDecimal()).toBe(n.getRaw().toDecimal());
+        }
+
+        expect(Fixed64.parse("0.00000000023283064365386962890625").getRaw().toNumber()).toBe(1);
+        expect(Fixed64.parse("-2.5", 0).toNumber()).toBe(-2);
+    });
+
+    it("should round ties to even for numbers and strings alike", () => {
+        for (const v of [2.5, 3.5, -0.5, -1.5]) {
+            expect(new Fixed64(v, 0).toNumber()).toBe(new Fixed64(String(v), 0).toNumber());
+        }
+
+        expect(new Fixed64(2.5, 0).toNumber()).toBe(2);
+        expect(new Fixed64("3.5", 0).toNumber()).toBe(4);
+        expect(new Fixed64(-0.5, 0).toNumber()).toBe(0);
+        expect(new Fixed64(1, 0).add(1.5).toNumber()).toBe(new Fixed64(1, 0).add(new Fixed64("1.5", 0)).toNumber());
+    });
+});
+
+describe("Checked", () => {
+    it("should throw on overflow", () => {
+        expect(checkedAdd("9223372036854775806", 1).toDecimal()).toBe("9223372036854775807");
+        expect(() => checkedAdd("9223372036854775807", 1)).toThrow(RangeError);
+        expect(() => checkedMultiply(4294967296, 2147483648)).toThrow(RangeError);
+        expect(() => checkedNeg("-9223372036854775808")).toThrow(RangeError);
+
+        const n = new Int64(5);
+
+        expect(n.checkedAdd(2).checkedMultiply(3).toNumber()).toBe(21);
+        expect(() => n.checkedDivide(0)).toThrow(RangeError);
+        expect(() => n.checkedShl(62)).toThrow(RangeError);
+        expect(n.toNumber()).toBe(21);
+    });
+});
+
+describe("Saturating", () => {
+    it("should saturate at the boundaries", () => {
+        expect(saturatingAdd("9223372036854775807", 1).toDecimal()).toBe("9223372036854775807");
+        expect(saturatingNeg("-9223372036854775808").toDecimal()).toBe("9223372036854775807");
+
+        const n = new Int64("-9223372036854775800");
+
+        expect(n.saturatingSubtract(100).toDecimal()).toBe("-9223372036854775808");
+        expect(n.saturatingMultiply(-1).toDecimal()).toBe("9223372036854775807");
+        expect(n.set(-3).saturatingPow(41).toDecimal()).toBe("-9223372036854775808");
+    });
+});
+
+describe("Overflowing", () => {
+    it("should report overflows", () => {
+        const r = overflowingAdd("9223372036854775807", 1);
+
+        expect(r.value.toDecimal()).toBe("-9223372036854775808");
+        expect(r.overflowed).toBe(true);
+
+        const n = new Int64(3);
+        const q = n.overflowingMultiply(4);
+
+        expect(q.value.toNumber()).toBe(12);
+        expect(q.overflowed).toBe(false);
+        expect(n.toNumber()).toBe(12);
+
+        expect(n.overflowingShl(65).overflowed).toBe(true);
+    });
+});
+
+describe("Errors", () => {
+    it("should have codes", () => {
+        expect(catchError(() => add("12a4", 1))).toMatchObject({
+            code: ErrorCode.ParseError,
+            input: "12a4",
+            position: 2,
+        });
+
+        expect(catchError(() => add("99999999999999999999", 1))).toMatchObject({
+            code: ErrorCode.OutOfRange,
+            position: 18,
+        });
+
+        expect(catchError(() => add(Buffer.alloc(3), 1))).toMatchObject({ code: ErrorCode.BadBufferLength });
+        expect(catchError(() => add({} as unknown as number, 1))).toMatchObject({ code: ErrorCode.UnsupportedType });
+        expect(catchError(() => checkedAdd("9223372036854775807", 1))).toMatchObject({ code: ErrorCode.Overflow });
+
+        expect(catchError(() => divide(1, 0n))).toMatchObject({ code: ErrorCode.DivideByZero, input: 0n });
+        expect((catchError(() => new Int64(1).divide(new Int64(0))) as { input: Int64 }).input.toNumber()).toBe(0);
+    });
+});
+
+describe("Buffer", () => {
+    it("should read and write buffers", () => {
+        const buffer = Buffer.alloc(12);
+        const n = new Int64("0x0102030405060708");
+
+        expect(n.writeToBuffer(buffer, 2, Endianness.Big)).toBe(10);
+        expect(buffer.toString("hex")).toBe("000001020304050607080000");
+        expect(n.toBuffer(Endianness.Big).toString("hex")).toBe("0102030405060708");
+        expect(n.toBuffer().toString("hex")).toBe("0807060504030201");
+
+        expect(Int64.fromBuffer(buffer, 2, Endianness.Big).eq(n)).toBe(true);
+        expect(Int64.fromBuffer(buffer, 2).toHex(true)).toBe("0x0807060504030201");
+
+        expect(catchError(() => Int64.fromBuffer(buffer, 5))).toMatchObject({ code: ErrorCode.OutOfRange });
+    });
+});
+
+describe("Radix", () => {
+    it("should parse and format in any radix", () => {
+        const n = Int64.parse("-zz", 36);
+
+        expect(n.toNumber()).toBe(-1295);
+        expect(n.toString(36)).toBe("-zz");
+        expect(n.toString(2)).toBe("-10100001111");
+        expect(n.toString()).toBe("-1295");
+
+        expect(parse("1y2p0ij32e8e7", 36).toDecimal()).toBe("9223372036854775807");
+        expect(format("-9223372036854775808", 16)).toBe("-8000000000000000");
+        expect(uint64.format("18446744073709551615", 32)).toBe("fvvvvvvvvvvvv");
+
+        expect(catchError(() => parse("1g", 16))).toMatchObject({ code: ErrorCode.ParseError, position: 1 });
+        expect(() => n.toString(37)).toThrow(RangeError);
+    });
+});
+
+describe("Lenient", () => {
+    it("should parse lenient number literals", () => {
+        expect(parse("1_000_000", { lenient: true }).toNumber()).toBe(1000000);
+        expect(parse(" 42 ", { lenient: true }).toNumber()).toBe(42);
+        expect(parse("+0x10", { lenient: true }).toNumber()).toBe(16);
+        expect(parse("-0XFF", { lenient: true }).toNumber()).toBe(-255);
+        expect(parse("0xff", { radix: 16, lenient: true }).toNumber()).toBe(255);
+
+        expect(catchError(() => parse("1__0", { lenient: true }))).toMatchObject({ code: ErrorCode.ParseError, position: 2 });
+        expect(catchError(() => parse("1_000"))).toMatchObject({ code: ErrorCode.ParseError, position: 1 });
+        expect(() => parse("-0xFF")).toThrow(TypeError);
+    });
+});